@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("4jjWdtjsh1aKj7HMbVMt7Ti2vGycJ5AEimYVXTQZsSrX");
+
+/// Minimal depositing program for localnet tests. `relay` forwards an
+/// already-encoded instruction to the pit program, signing for this
+/// program's `[b"pit_caller"]` PDA wherever it appears in the accounts.
+#[program]
+pub mod mock_pit_caller {
+    use super::*;
+
+    /// Invoke `target_program` with `data`; `remaining_accounts` are its accounts
+    pub fn relay<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Relay<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let pit_caller = ctx.accounts.pit_caller.key();
+
+        let mut accounts = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(ctx.remaining_accounts.len() + 1);
+        for account in ctx.remaining_accounts {
+            let is_signer = account.is_signer || account.key() == pit_caller;
+            accounts.push(if account.is_writable {
+                AccountMeta::new(account.key(), is_signer)
+            } else {
+                AccountMeta::new_readonly(account.key(), is_signer)
+            });
+            account_infos.push(account.clone());
+        }
+        account_infos.push(ctx.accounts.target_program.to_account_info());
+
+        let ix = Instruction {
+            program_id: ctx.accounts.target_program.key(),
+            accounts,
+            data,
+        };
+        invoke_signed(
+            &ix,
+            &account_infos,
+            &[&[b"pit_caller".as_ref(), &[ctx.bumps.pit_caller]]],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Relay<'info> {
+    /// CHECK: Signing PDA the pit checks deposits against
+    #[account(seeds = [b"pit_caller"], bump)]
+    pub pit_caller: UncheckedAccount<'info>,
+
+    /// CHECK: Program receiving the relayed instruction
+    #[account(executable)]
+    pub target_program: UncheckedAccount<'info>,
+}
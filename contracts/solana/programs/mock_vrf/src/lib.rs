@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("HVKZjNXSdGMNqR2wP1KNGi4Z35euHbo2T6oEyrJ7TPbF");
+
+/// Minimal VRF oracle for localnet tests. Requests are only logged; the test
+/// plays the oracle by calling `fulfill` with the randomness of its choice,
+/// which is forwarded to the consumer's `fulfill_randomness` callback signed
+/// by this program's `[b"oracle_authority"]` PDA.
+#[program]
+pub mod mock_vrf {
+    use super::*;
+
+    /// Record a randomness request for `request` (the consumer's request account)
+    pub fn request_randomness(ctx: Context<RequestRandomness>, seed: [u8; 32]) -> Result<()> {
+        emit!(RandomnessRequested {
+            request: ctx.accounts.request.key(),
+            seed,
+        });
+
+        Ok(())
+    }
+
+    /// Deliver `randomness` to the consumer program. `remaining_accounts` are
+    /// passed to the callback after the oracle authority.
+    pub fn fulfill<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Fulfill<'info>>,
+        randomness: [u8; 32],
+    ) -> Result<()> {
+        let oracle_authority = &ctx.accounts.oracle_authority;
+
+        let mut data = hash(b"global:fulfill_randomness").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&randomness);
+
+        let mut accounts = vec![AccountMeta::new_readonly(oracle_authority.key(), true)];
+        let mut account_infos = vec![oracle_authority.to_account_info()];
+        for account in ctx.remaining_accounts {
+            accounts.push(if account.is_writable {
+                AccountMeta::new(account.key(), account.is_signer)
+            } else {
+                AccountMeta::new_readonly(account.key(), account.is_signer)
+            });
+            account_infos.push(account.clone());
+        }
+        account_infos.push(ctx.accounts.consumer_program.to_account_info());
+
+        let ix = Instruction {
+            program_id: ctx.accounts.consumer_program.key(),
+            accounts,
+            data,
+        };
+        invoke_signed(
+            &ix,
+            &account_infos,
+            &[&[b"oracle_authority".as_ref(), &[ctx.bumps.oracle_authority]]],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RequestRandomness<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Consumer-owned request account, only logged
+    pub request: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Fulfill<'info> {
+    /// CHECK: Signing PDA the consumer checks callbacks against
+    #[account(seeds = [b"oracle_authority"], bump)]
+    pub oracle_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Program receiving the callback
+    #[account(executable)]
+    pub consumer_program: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct RandomnessRequested {
+    pub request: Pubkey,
+    pub seed: [u8; 32],
+}
@@ -1,6 +1,22 @@
 use anchor_lang::prelude::*;
 use crate::state::CollectionConfig;
 
+#[derive(Accounts)]
+pub struct SetSacredWastePit<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ AdminError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+}
+
 #[derive(Accounts)]
 pub struct SetTreasury<'info> {
     #[account(
@@ -11,7 +27,7 @@ pub struct SetTreasury<'info> {
 
     #[account(
         mut,
-        seeds = [b"collection_config"],
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
         bump = collection_config.bump
     )]
     pub collection_config: Account<'info, CollectionConfig>,
@@ -27,12 +43,106 @@ pub struct SetPremiumFee<'info> {
 
     #[account(
         mut,
-        seeds = [b"collection_config"],
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetTemplateShare<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ AdminError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetParentShare<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ AdminError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetBackendSigner<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ AdminError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetVrfOracle<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ AdminError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetAllowedUriPrefixes<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ AdminError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
         bump = collection_config.bump
     )]
     pub collection_config: Account<'info, CollectionConfig>,
 }
 
+pub fn handler_set_pit(ctx: Context<SetSacredWastePit>, pit: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.collection_config;
+    let old_pit = config.sacred_waste_pit;
+    config.sacred_waste_pit = Some(pit);
+
+    msg!("Sacred Waste Pit updated");
+    emit!(SacredWastePitUpdated {
+        old_pit,
+        new_pit: Some(pit),
+    });
+
+    Ok(())
+}
+
 pub fn handler_set_treasury(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
     let config = &mut ctx.accounts.collection_config;
     let old_treasury = config.treasury;
@@ -61,6 +171,106 @@ pub fn handler_set_premium_fee(ctx: Context<SetPremiumFee>, fee: u64) -> Result<
     Ok(())
 }
 
+pub fn handler_set_template_share(ctx: Context<SetTemplateShare>, share_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.collection_config;
+    // Template and parent shares are both carved out of the same premium fee
+    require!(
+        share_bps as u32 + config.parent_share_bps as u32 <= 10_000,
+        AdminError::InvalidShareBps
+    );
+
+    let old_share_bps = config.template_share_bps;
+    config.template_share_bps = share_bps;
+
+    msg!("Template share updated: {} -> {} bps", old_share_bps, share_bps);
+    emit!(TemplateShareUpdated {
+        old_share_bps,
+        new_share_bps: share_bps,
+    });
+
+    Ok(())
+}
+
+pub fn handler_set_parent_share(ctx: Context<SetParentShare>, share_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.collection_config;
+    require!(
+        share_bps as u32 + config.template_share_bps as u32 <= 10_000,
+        AdminError::InvalidShareBps
+    );
+
+    let old_share_bps = config.parent_share_bps;
+    config.parent_share_bps = share_bps;
+
+    msg!("Parent share updated: {} -> {} bps", old_share_bps, share_bps);
+    emit!(ParentShareUpdated {
+        old_share_bps,
+        new_share_bps: share_bps,
+    });
+
+    Ok(())
+}
+
+pub fn handler_set_backend_signer(
+    ctx: Context<SetBackendSigner>,
+    backend_signer: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.collection_config;
+    let old_signer = config.backend_signer;
+    config.backend_signer = backend_signer;
+
+    msg!("Backend signer updated");
+    emit!(BackendSignerUpdated {
+        old_signer,
+        new_signer: backend_signer,
+    });
+
+    Ok(())
+}
+
+pub fn handler_set_vrf_oracle(ctx: Context<SetVrfOracle>, vrf_oracle: Option<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.collection_config;
+    let old_oracle = config.vrf_oracle;
+    config.vrf_oracle = vrf_oracle;
+
+    msg!("VRF oracle updated");
+    emit!(VrfOracleUpdated {
+        old_oracle,
+        new_oracle: vrf_oracle,
+    });
+
+    Ok(())
+}
+
+pub fn handler_set_allowed_uri_prefixes(
+    ctx: Context<SetAllowedUriPrefixes>,
+    prefixes: Vec<String>,
+) -> Result<()> {
+    require!(
+        prefixes.len() <= CollectionConfig::MAX_URI_PREFIXES,
+        AdminError::TooManyUriPrefixes
+    );
+    require!(
+        prefixes.iter().all(
+            |prefix| !prefix.is_empty() && prefix.len() <= CollectionConfig::MAX_URI_PREFIX_LEN
+        ),
+        AdminError::InvalidUriPrefix
+    );
+
+    let config = &mut ctx.accounts.collection_config;
+    config.allowed_uri_prefixes = prefixes.clone();
+
+    msg!("Allowed URI prefixes updated: {}", prefixes.len());
+    emit!(AllowedUriPrefixesUpdated { prefixes });
+
+    Ok(())
+}
+
+#[event]
+pub struct SacredWastePitUpdated {
+    pub old_pit: Option<Pubkey>,
+    pub new_pit: Option<Pubkey>,
+}
+
 #[event]
 pub struct TreasuryUpdated {
     pub old_treasury: Pubkey,
@@ -73,8 +283,43 @@ pub struct PremiumFeeUpdated {
     pub new_fee: u64,
 }
 
+#[event]
+pub struct TemplateShareUpdated {
+    pub old_share_bps: u16,
+    pub new_share_bps: u16,
+}
+
+#[event]
+pub struct ParentShareUpdated {
+    pub old_share_bps: u16,
+    pub new_share_bps: u16,
+}
+
+#[event]
+pub struct BackendSignerUpdated {
+    pub old_signer: Option<Pubkey>,
+    pub new_signer: Option<Pubkey>,
+}
+
+#[event]
+pub struct VrfOracleUpdated {
+    pub old_oracle: Option<Pubkey>,
+    pub new_oracle: Option<Pubkey>,
+}
+
+#[event]
+pub struct AllowedUriPrefixesUpdated {
+    pub prefixes: Vec<String>,
+}
+
 #[error_code]
 pub enum AdminError {
     #[msg("Caller is not the authority")]
     Unauthorized,
+    #[msg("Too many allowed URI prefixes")]
+    TooManyUriPrefixes,
+    #[msg("URI prefixes must be 1-64 bytes")]
+    InvalidUriPrefix,
+    #[msg("Template and parent shares must total at most 10000 bps")]
+    InvalidShareBps,
 }
@@ -0,0 +1,819 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{
+    self, Mint, Token, TokenAccount, Burn, CloseAccount, MintTo, FreezeAccount, SetAuthority,
+};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::instructions::{BurnNftCpi, BurnNftCpiAccounts};
+
+use crate::instructions::pit::{record_pit_burn, PitBurn};
+use crate::instructions::leaderboard::update_leaderboard;
+use crate::instructions::mint::create_metadata_account;
+use crate::instructions::rewards::pay_pit_burn_reward;
+use crate::instructions::seasons::record_season_burn;
+use crate::state::{
+    CollectionConfig, BurnedArt, SacredWastePit, PitBurnRecord, BurnerStats, AllowedCollection,
+    PitRewards, PitBurnBucket, BurnLeaderboard, Season, SeasonBurnerStats,
+    BurnReason,
+};
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = burner,
+        constraint = token_account.amount == 1 @ BurnError::NotOwner
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = burner,
+        space = BurnedArt::LEN,
+        seeds = [b"burned_art", mint.key().as_ref()],
+        bump
+    )]
+    pub burned_art: Box<Account<'info, BurnedArt>>,
+
+    /// CHECK: Metadata PDA of the mint, deserialized in the handler
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnToWaste<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump,
+        constraint = collection_config.sacred_waste_pit == Some(sacred_waste_pit.key()) @ BurnError::PitNotConfigured
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Box<Account<'info, SacredWastePit>>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = burner,
+        constraint = token_account.amount == 1 @ BurnError::NotOwner
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = burner,
+        space = BurnedArt::LEN,
+        seeds = [b"burned_art", mint.key().as_ref()],
+        bump
+    )]
+    pub burned_art: Box<Account<'info, BurnedArt>>,
+
+    /// Required in `Records` storage mode
+    #[account(
+        init,
+        payer = burner,
+        space = PitBurnRecord::LEN,
+        seeds = [b"pit_burn", sacred_waste_pit.total_burns.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pit_burn_record: Option<Box<Account<'info, PitBurnRecord>>>,
+
+    /// Current bucket, required in `Buckets` storage mode
+    #[account(mut)]
+    pub pit_bucket: Option<AccountLoader<'info, PitBurnBucket>>,
+
+    #[account(
+        init_if_needed,
+        payer = burner,
+        space = BurnerStats::LEN,
+        seeds = [b"burner_stats", burner.key().as_ref()],
+        bump
+    )]
+    pub burner_stats: Box<Account<'info, BurnerStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump = burn_leaderboard.bump
+    )]
+    pub burn_leaderboard: Box<Account<'info, BurnLeaderboard>>,
+
+    /// Active season, required while a season is open
+    #[account(
+        mut,
+        seeds = [b"season", sacred_waste_pit.current_season.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(
+        init_if_needed,
+        payer = burner,
+        space = SeasonBurnerStats::LEN,
+        seeds = [
+            b"season_stats",
+            sacred_waste_pit.current_season.to_le_bytes().as_ref(),
+            burner.key().as_ref()
+        ],
+        bump
+    )]
+    pub season_stats: Option<Box<Account<'info, SeasonBurnerStats>>>,
+
+    /// Required once pit rewards are initialized
+    #[account(
+        mut,
+        seeds = [b"pit_rewards"],
+        bump = pit_rewards.bump
+    )]
+    pub pit_rewards: Option<Box<Account<'info, PitRewards>>>,
+
+    /// Reward vault, checked against `pit_rewards.vault`
+    #[account(mut)]
+    pub reward_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Burner's reward token account, checked against the reward mint
+    #[account(mut)]
+    pub burner_reward_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Metadata PDA of the mint, deserialized in the handler
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Soul-bound certificate for this burn, minted when the certificate
+    /// accounts are supplied
+    #[account(
+        init,
+        payer = burner,
+        mint::decimals = 0,
+        mint::authority = collection_config,
+        mint::freeze_authority = collection_config,
+        seeds = [b"burn_certificate", mint.key().as_ref()],
+        bump
+    )]
+    pub certificate_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = burner,
+        associated_token::mint = certificate_mint,
+        associated_token::authority = burner
+    )]
+    pub certificate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Metadata PDA of the certificate mint, created via CPI
+    #[account(mut)]
+    pub certificate_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub rent: Option<Sysvar<'info, Rent>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnForeignToPit<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Box<Account<'info, SacredWastePit>>,
+
+    #[account(
+        seeds = [b"allowed_collection", allowed_collection.collection_mint.as_ref()],
+        bump = allowed_collection.bump
+    )]
+    pub allowed_collection: Box<Account<'info, AllowedCollection>>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = burner,
+        constraint = token_account.amount == 1 @ BurnError::NotOwner
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata PDA of the mint, deserialized in the handler and burned via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition PDA of the mint, burned via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata PDA of the collection mint, its size is decremented via CPI
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            allowed_collection.collection_mint.as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// Required in `Records` storage mode
+    #[account(
+        init,
+        payer = burner,
+        space = PitBurnRecord::LEN,
+        seeds = [b"pit_burn", sacred_waste_pit.total_burns.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pit_burn_record: Option<Box<Account<'info, PitBurnRecord>>>,
+
+    /// Current bucket, required in `Buckets` storage mode
+    #[account(mut)]
+    pub pit_bucket: Option<AccountLoader<'info, PitBurnBucket>>,
+
+    #[account(
+        init_if_needed,
+        payer = burner,
+        space = BurnerStats::LEN,
+        seeds = [b"burner_stats", burner.key().as_ref()],
+        bump
+    )]
+    pub burner_stats: Box<Account<'info, BurnerStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump = burn_leaderboard.bump
+    )]
+    pub burn_leaderboard: Box<Account<'info, BurnLeaderboard>>,
+
+    /// Active season, required while a season is open
+    #[account(
+        mut,
+        seeds = [b"season", sacred_waste_pit.current_season.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(
+        init_if_needed,
+        payer = burner,
+        space = SeasonBurnerStats::LEN,
+        seeds = [
+            b"season_stats",
+            sacred_waste_pit.current_season.to_le_bytes().as_ref(),
+            burner.key().as_ref()
+        ],
+        bump
+    )]
+    pub season_stats: Option<Box<Account<'info, SeasonBurnerStats>>>,
+
+    /// Required once pit rewards are initialized
+    #[account(
+        mut,
+        seeds = [b"pit_rewards"],
+        bump = pit_rewards.bump
+    )]
+    pub pit_rewards: Option<Box<Account<'info, PitRewards>>>,
+
+    /// Reward vault, checked against `pit_rewards.vault`
+    #[account(mut)]
+    pub reward_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Burner's reward token account, checked against the reward mint
+    #[account(mut)]
+    pub burner_reward_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn handler_burn(ctx: Context<BurnNft>) -> Result<()> {
+    let config = &mut ctx.accounts.collection_config;
+    let clock = Clock::get()?;
+
+    let token_uri = read_collection_uri(&ctx.accounts.metadata, &config.key())?;
+
+    // Store burned art record
+    let burned_art = &mut ctx.accounts.burned_art;
+    burned_art.artist = ctx.accounts.burner.key();
+    burned_art.token_uri = token_uri.clone();
+    burned_art.burned_at = clock.unix_timestamp;
+    burned_art.original_mint = ctx.accounts.mint.key();
+    burned_art.bump = ctx.bumps.burned_art;
+
+    // Update collection stats (checked arithmetic to prevent overflow)
+    config.total_burned = config.total_burned.checked_add(1).ok_or(BurnError::MathOverflow)?;
+
+    burn_and_close(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_account,
+        &ctx.accounts.burner,
+    )?;
+
+    msg!("NFT burned: {}", ctx.accounts.mint.key());
+    emit!(ArtBurned {
+        artist: ctx.accounts.burner.key(),
+        token_uri,
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
+
+pub fn handler_to_waste(
+    ctx: Context<BurnToWaste>,
+    reason: BurnReason,
+    message: Option<String>,
+) -> Result<()> {
+    validate_burn_message(&message)?;
+
+    let config = &mut ctx.accounts.collection_config;
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    let clock = Clock::get()?;
+
+    let token_uri = read_collection_uri(&ctx.accounts.metadata, &config.key())?;
+
+    // Store burned art record
+    let burned_art = &mut ctx.accounts.burned_art;
+    burned_art.artist = ctx.accounts.burner.key();
+    burned_art.token_uri = token_uri.clone();
+    burned_art.burned_at = clock.unix_timestamp;
+    burned_art.original_mint = ctx.accounts.mint.key();
+    burned_art.bump = ctx.bumps.burned_art;
+
+    let burn_id = pit.total_burns;
+    record_pit_burn(
+        pit.storage_mode,
+        &mut ctx.accounts.pit_burn_record,
+        ctx.bumps.pit_burn_record,
+        &ctx.accounts.pit_bucket,
+        PitBurn {
+            burner: ctx.accounts.burner.key(),
+            mint: ctx.accounts.mint.key(),
+            metadata: token_uri,
+            source_collection: Some(config.key()),
+            reason: Some(reason),
+            message: message.clone(),
+            timestamp: clock.unix_timestamp,
+            burn_id,
+        },
+    )?;
+
+    // Update burner stats
+    let stats = &mut ctx.accounts.burner_stats;
+    if stats.burner == Pubkey::default() {
+        stats.burner = ctx.accounts.burner.key();
+        stats.bump = ctx.bumps.burner_stats;
+    }
+    stats.burn_count = stats.burn_count.checked_add(1).ok_or(BurnError::MathOverflow)?;
+    update_leaderboard(&mut ctx.accounts.burn_leaderboard, stats.burner, stats.burn_count)?;
+    record_season_burn(
+        pit,
+        &mut ctx.accounts.season,
+        &mut ctx.accounts.season_stats,
+        ctx.bumps.season_stats,
+        stats.burner,
+        clock.unix_timestamp,
+    )?;
+
+    pay_pit_burn_reward(
+        &pit.to_account_info(),
+        pit.bump,
+        pit.rewards_enabled,
+        &mut ctx.accounts.pit_rewards,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.burner_reward_account,
+        stats,
+        &ctx.accounts.token_program,
+        clock.unix_timestamp,
+        false,
+    )?;
+
+    match (
+        &ctx.accounts.certificate_mint,
+        &ctx.accounts.certificate_token_account,
+        &ctx.accounts.certificate_metadata,
+        &ctx.accounts.token_metadata_program,
+        &ctx.accounts.rent,
+    ) {
+        (
+            Some(certificate_mint),
+            Some(certificate_token_account),
+            Some(certificate_metadata),
+            Some(token_metadata_program),
+            Some(rent),
+        ) => {
+            mint_burn_certificate(
+                config,
+                &ctx.accounts.burner,
+                certificate_mint,
+                certificate_token_account,
+                certificate_metadata,
+                token_metadata_program,
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program,
+                rent,
+                ctx.accounts.mint.key(),
+                burn_id,
+            )?;
+            stats.certificates_minted = stats
+                .certificates_minted
+                .checked_add(1)
+                .ok_or(BurnError::MathOverflow)?;
+        }
+        (None, None, None, None, None) => {}
+        _ => return err!(BurnError::IncompleteCertificateAccounts),
+    }
+
+    // Update counts (checked arithmetic to prevent overflow)
+    config.total_burned = config.total_burned.checked_add(1).ok_or(BurnError::MathOverflow)?;
+    pit.total_burns = pit.total_burns.checked_add(1).ok_or(BurnError::MathOverflow)?;
+
+    burn_and_close(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_account,
+        &ctx.accounts.burner,
+    )?;
+
+    msg!("NFT burned to Sacred Waste: {}", ctx.accounts.mint.key());
+    emit!(BurnedToSacredWaste {
+        artist: ctx.accounts.burner.key(),
+        burn_id,
+        mint: ctx.accounts.mint.key(),
+        reason,
+        message,
+    });
+
+    Ok(())
+}
+
+pub fn handler_foreign_to_pit(ctx: Context<BurnForeignToPit>) -> Result<()> {
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    let clock = Clock::get()?;
+    let source_collection = ctx.accounts.allowed_collection.collection_mint;
+
+    // Only verified members of an allowlisted collection are accepted
+    let token_uri = {
+        let data = ctx.accounts.metadata.try_borrow_data()?;
+        let metadata = Metadata::safe_deserialize(&data).map_err(|_| BurnError::InvalidMetadata)?;
+        let collection = metadata.collection.ok_or(BurnError::CollectionNotVerified)?;
+        require!(collection.verified, BurnError::CollectionNotVerified);
+        require_keys_eq!(
+            collection.key,
+            source_collection,
+            BurnError::CollectionNotAllowed
+        );
+        metadata.uri.trim_end_matches('\0').to_string()
+    };
+
+    let burn_id = pit.total_burns;
+    record_pit_burn(
+        pit.storage_mode,
+        &mut ctx.accounts.pit_burn_record,
+        ctx.bumps.pit_burn_record,
+        &ctx.accounts.pit_bucket,
+        PitBurn {
+            burner: ctx.accounts.burner.key(),
+            mint: ctx.accounts.mint.key(),
+            metadata: token_uri,
+            source_collection: Some(source_collection),
+            reason: None,
+            message: None,
+            timestamp: clock.unix_timestamp,
+            burn_id,
+        },
+    )?;
+
+    // Update burner stats
+    let stats = &mut ctx.accounts.burner_stats;
+    if stats.burner == Pubkey::default() {
+        stats.burner = ctx.accounts.burner.key();
+        stats.bump = ctx.bumps.burner_stats;
+    }
+    stats.burn_count = stats.burn_count.checked_add(1).ok_or(BurnError::MathOverflow)?;
+    update_leaderboard(&mut ctx.accounts.burn_leaderboard, stats.burner, stats.burn_count)?;
+    record_season_burn(
+        pit,
+        &mut ctx.accounts.season,
+        &mut ctx.accounts.season_stats,
+        ctx.bumps.season_stats,
+        stats.burner,
+        clock.unix_timestamp,
+    )?;
+
+    pay_pit_burn_reward(
+        &pit.to_account_info(),
+        pit.bump,
+        pit.rewards_enabled,
+        &mut ctx.accounts.pit_rewards,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.burner_reward_account,
+        stats,
+        &ctx.accounts.token_program,
+        clock.unix_timestamp,
+        false,
+    )?;
+
+    pit.total_burns = pit.total_burns.checked_add(1).ok_or(BurnError::MathOverflow)?;
+
+    // Burn through Token Metadata so the metadata and edition accounts go too
+    BurnNftCpi::new(
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        BurnNftCpiAccounts {
+            metadata: &ctx.accounts.metadata.to_account_info(),
+            owner: &ctx.accounts.burner.to_account_info(),
+            mint: &ctx.accounts.mint.to_account_info(),
+            token_account: &ctx.accounts.token_account.to_account_info(),
+            master_edition_account: &ctx.accounts.master_edition.to_account_info(),
+            spl_token_program: &ctx.accounts.token_program.to_account_info(),
+            collection_metadata: Some(&ctx.accounts.collection_metadata.to_account_info()),
+        },
+    )
+    .invoke()?;
+
+    msg!("Foreign NFT burned to Sacred Waste: {}", ctx.accounts.mint.key());
+    emit!(ForeignBurnedToSacredWaste {
+        burner: ctx.accounts.burner.key(),
+        burn_id,
+        mint: ctx.accounts.mint.key(),
+        source_collection,
+    });
+
+    Ok(())
+}
+
+/// Reject an empty or overlong burn message
+pub(crate) fn validate_burn_message(message: &Option<String>) -> Result<()> {
+    if let Some(message) = message {
+        require!(!message.is_empty(), BurnError::EmptyMessage);
+        require!(
+            message.len() <= PitBurnRecord::MAX_MESSAGE_LEN,
+            BurnError::MessageTooLong
+        );
+    }
+
+    Ok(())
+}
+
+/// Deserialize the mint's Metaplex metadata, check it was minted by this
+/// collection (the config PDA is its update authority) and return its URI
+pub(crate) fn read_collection_uri(
+    metadata: &AccountInfo,
+    collection_config: &Pubkey,
+) -> Result<String> {
+    let data = metadata.try_borrow_data()?;
+    let metadata = Metadata::safe_deserialize(&data).map_err(|_| BurnError::InvalidMetadata)?;
+    require_keys_eq!(
+        metadata.update_authority,
+        *collection_config,
+        BurnError::NotInCollection
+    );
+
+    Ok(metadata.uri.trim_end_matches('\0').to_string())
+}
+
+/// Mint a frozen, fixed-supply certificate of `burn_id` to the burner. The
+/// certificate mint is seeded by the burned mint; its name carries the burn
+/// ID and its URI the burned mint.
+#[allow(clippy::too_many_arguments)]
+fn mint_burn_certificate<'info>(
+    config: &Account<'info, CollectionConfig>,
+    burner: &Signer<'info>,
+    certificate_mint: &Account<'info, Mint>,
+    certificate_token_account: &Account<'info, TokenAccount>,
+    certificate_metadata: &UncheckedAccount<'info>,
+    token_metadata_program: &UncheckedAccount<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    burned_mint: Pubkey,
+    burn_id: u64,
+) -> Result<()> {
+    let name = format!("Burn Cert #{}", burn_id);
+    let uri = format!("{}burned/{}.json", config.uri, burned_mint);
+    require!(
+        uri.len() <= mpl_token_metadata::MAX_URI_LENGTH,
+        BurnError::CertificateUriTooLong
+    );
+
+    let seeds = &[
+        b"collection_config".as_ref(),
+        config.slug.as_bytes(),
+        &[config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: certificate_mint.to_account_info(),
+                to: certificate_token_account.to_account_info(),
+                authority: config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    create_metadata_account(
+        certificate_metadata,
+        &certificate_mint.to_account_info(),
+        &config.to_account_info(),
+        &burner.to_account_info(),
+        &system_program.to_account_info(),
+        &rent.to_account_info(),
+        token_metadata_program,
+        name,
+        config.symbol.clone(),
+        uri,
+        signer_seeds,
+    )?;
+
+    // Frozen so it cannot be transferred, and capped at the one minted token
+    token::freeze_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        FreezeAccount {
+            account: certificate_token_account.to_account_info(),
+            mint: certificate_mint.to_account_info(),
+            authority: config.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SetAuthority {
+                current_authority: config.to_account_info(),
+                account_or_mint: certificate_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    emit!(BurnCertificateMinted {
+        burner: burner.key(),
+        certificate_mint: certificate_mint.key(),
+        burned_mint,
+        burn_id,
+    });
+
+    Ok(())
+}
+
+/// Burn the single token and return the token account's rent to the burner
+fn burn_and_close<'info>(
+    token_program: &Program<'info, Token>,
+    mint: &Account<'info, Mint>,
+    token_account: &Account<'info, TokenAccount>,
+    burner: &Signer<'info>,
+) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: token_account.to_account_info(),
+                authority: burner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    token::close_account(
+        CpiContext::new(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: token_account.to_account_info(),
+                destination: burner.to_account_info(),
+                authority: burner.to_account_info(),
+            },
+        ),
+    )?;
+
+    Ok(())
+}
+
+#[event]
+pub struct ArtBurned {
+    pub artist: Pubkey,
+    pub token_uri: String,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BurnedToSacredWaste {
+    pub artist: Pubkey,
+    pub burn_id: u64,
+    pub mint: Pubkey,
+    pub reason: BurnReason,
+    pub message: Option<String>,
+}
+
+#[event]
+pub struct BurnCertificateMinted {
+    pub burner: Pubkey,
+    pub certificate_mint: Pubkey,
+    pub burned_mint: Pubkey,
+    pub burn_id: u64,
+}
+
+#[event]
+pub struct ForeignBurnedToSacredWaste {
+    pub burner: Pubkey,
+    pub burn_id: u64,
+    pub mint: Pubkey,
+    pub source_collection: Pubkey,
+}
+
+#[error_code]
+pub enum BurnError {
+    #[msg("You do not own this NFT")]
+    NotOwner,
+    #[msg("Sacred Waste Pit is not configured")]
+    PitNotConfigured,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Metadata account could not be deserialized")]
+    InvalidMetadata,
+    #[msg("NFT does not belong to this collection")]
+    NotInCollection,
+    #[msg("NFT is not a verified member of a collection")]
+    CollectionNotVerified,
+    #[msg("NFT's collection is not on the pit allowlist")]
+    CollectionNotAllowed,
+    #[msg("Burn message must not be empty")]
+    EmptyMessage,
+    #[msg("Burn message is too long")]
+    MessageTooLong,
+    #[msg("Certificate accounts must be supplied together")]
+    IncompleteCertificateAccounts,
+    #[msg("Certificate URI is too long")]
+    CertificateUriTooLong,
+}
@@ -0,0 +1,524 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Burn, CloseAccount, Transfer};
+
+use crate::instructions::burn::{
+    read_collection_uri, validate_burn_message, BurnError, BurnedToSacredWaste,
+};
+use crate::instructions::leaderboard::update_leaderboard;
+use crate::instructions::pit::{record_pit_burn, PitBurn};
+use crate::instructions::rewards::pay_pit_burn_reward;
+use crate::instructions::seasons::record_season_burn;
+use crate::state::{
+    CollectionConfig, BurnedArt, SacredWastePit, PitBurnRecord, BurnerStats, PitRewards,
+    PitBurnBucket, BurnLeaderboard, Season, SeasonBurnerStats, BurnReason, ScheduledBurn,
+    PitStorageMode,
+};
+
+#[derive(Accounts)]
+pub struct ScheduleBurn<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump,
+        constraint = collection_config.sacred_waste_pit == Some(sacred_waste_pit.key())
+            @ BurnError::PitNotConfigured
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    /// Provides the cancel window
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Box<Account<'info, SacredWastePit>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = burner,
+        constraint = token_account.amount == 1 @ BurnError::NotOwner
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata PDA of the mint, deserialized in the handler
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = burner,
+        space = ScheduledBurn::LEN,
+        seeds = [b"scheduled_burn", mint.key().as_ref()],
+        bump
+    )]
+    pub scheduled_burn: Box<Account<'info, ScheduledBurn>>,
+
+    #[account(
+        init,
+        payer = burner,
+        token::mint = mint,
+        token::authority = scheduled_burn,
+        seeds = [b"burn_escrow", mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBurn<'info> {
+    #[account(
+        mut,
+        constraint = burner.key() == scheduled_burn.burner @ DelayedBurnError::NotBurner
+    )]
+    pub burner: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = burner,
+        associated_token::mint = mint,
+        associated_token::authority = burner
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = burner,
+        seeds = [b"scheduled_burn", mint.key().as_ref()],
+        bump = scheduled_burn.bump
+    )]
+    pub scheduled_burn: Box<Account<'info, ScheduledBurn>>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_escrow", mint.key().as_ref()],
+        bump = scheduled_burn.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteBurn<'info> {
+    /// Anyone may execute a due burn; the executor pays for the burn records
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Checked against the scheduled burn; receives the escrow rent
+    #[account(
+        mut,
+        constraint = burner.key() == scheduled_burn.burner @ DelayedBurnError::NotBurner
+    )]
+    pub burner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump,
+        constraint = collection_config.key() == scheduled_burn.collection_config
+            @ BurnError::NotInCollection,
+        constraint = collection_config.sacred_waste_pit == Some(sacred_waste_pit.key())
+            @ BurnError::PitNotConfigured
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Box<Account<'info, SacredWastePit>>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = burner,
+        seeds = [b"scheduled_burn", mint.key().as_ref()],
+        bump = scheduled_burn.bump
+    )]
+    pub scheduled_burn: Box<Account<'info, ScheduledBurn>>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_escrow", mint.key().as_ref()],
+        bump = scheduled_burn.escrow_bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = BurnedArt::LEN,
+        seeds = [b"burned_art", mint.key().as_ref()],
+        bump
+    )]
+    pub burned_art: Box<Account<'info, BurnedArt>>,
+
+    /// Required in `Records` storage mode
+    #[account(
+        init,
+        payer = executor,
+        space = PitBurnRecord::LEN,
+        seeds = [b"pit_burn", sacred_waste_pit.total_burns.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pit_burn_record: Option<Box<Account<'info, PitBurnRecord>>>,
+
+    /// Current bucket, required in `Buckets` storage mode
+    #[account(mut)]
+    pub pit_bucket: Option<AccountLoader<'info, PitBurnBucket>>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = BurnerStats::LEN,
+        seeds = [b"burner_stats", burner.key().as_ref()],
+        bump
+    )]
+    pub burner_stats: Box<Account<'info, BurnerStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump = burn_leaderboard.bump
+    )]
+    pub burn_leaderboard: Box<Account<'info, BurnLeaderboard>>,
+
+    /// Active season, required while a season is open
+    #[account(
+        mut,
+        seeds = [b"season", sacred_waste_pit.current_season.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = SeasonBurnerStats::LEN,
+        seeds = [
+            b"season_stats",
+            sacred_waste_pit.current_season.to_le_bytes().as_ref(),
+            burner.key().as_ref()
+        ],
+        bump
+    )]
+    pub season_stats: Option<Box<Account<'info, SeasonBurnerStats>>>,
+
+    /// Required once pit rewards are initialized
+    #[account(
+        mut,
+        seeds = [b"pit_rewards"],
+        bump = pit_rewards.bump
+    )]
+    pub pit_rewards: Option<Box<Account<'info, PitRewards>>>,
+
+    /// Reward vault, checked against `pit_rewards.vault`
+    #[account(mut)]
+    pub reward_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Burner's reward token account, checked against the reward mint
+    #[account(mut)]
+    pub burner_reward_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Metadata PDA of the mint, deserialized in the handler
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetCancelWindow<'info> {
+    #[account(
+        constraint = authority.key() == sacred_waste_pit.authority @ DelayedBurnError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+}
+
+pub fn handler_schedule_burn(
+    ctx: Context<ScheduleBurn>,
+    reason: BurnReason,
+    message: Option<String>,
+) -> Result<()> {
+    validate_burn_message(&message)?;
+    read_collection_uri(&ctx.accounts.metadata, &ctx.accounts.collection_config.key())?;
+
+    let executes_after = Clock::get()?
+        .unix_timestamp
+        .checked_add(ctx.accounts.sacred_waste_pit.cancel_window)
+        .ok_or(BurnError::MathOverflow)?;
+
+    let scheduled = &mut ctx.accounts.scheduled_burn;
+    scheduled.burner = ctx.accounts.burner.key();
+    scheduled.mint = ctx.accounts.mint.key();
+    scheduled.collection_config = ctx.accounts.collection_config.key();
+    scheduled.executes_after = executes_after;
+    scheduled.reason = reason;
+    scheduled.message = message;
+    scheduled.escrow_bump = ctx.bumps.escrow;
+    scheduled.bump = ctx.bumps.scheduled_burn;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.burner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    msg!("Burn of {} scheduled after {}", ctx.accounts.mint.key(), executes_after);
+    emit!(BurnScheduled {
+        burner: ctx.accounts.burner.key(),
+        mint: ctx.accounts.mint.key(),
+        executes_after,
+    });
+
+    Ok(())
+}
+
+pub fn handler_cancel_burn(ctx: Context<CancelBurn>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp < ctx.accounts.scheduled_burn.executes_after,
+        DelayedBurnError::CancelWindowClosed
+    );
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"scheduled_burn".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.accounts.scheduled_burn.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.scheduled_burn.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.burner.to_account_info(),
+            authority: ctx.accounts.scheduled_burn.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!("Scheduled burn of {} cancelled", mint_key);
+    emit!(BurnCancelled {
+        burner: ctx.accounts.burner.key(),
+        mint: mint_key,
+    });
+
+    Ok(())
+}
+
+pub fn handler_execute_burn(ctx: Context<ExecuteBurn>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= ctx.accounts.scheduled_burn.executes_after,
+        DelayedBurnError::CancelWindowOpen
+    );
+
+    let config = &mut ctx.accounts.collection_config;
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    let burner = ctx.accounts.burner.key();
+    let reason = ctx.accounts.scheduled_burn.reason;
+    let message = ctx.accounts.scheduled_burn.message.clone();
+    // Past the cancel window a failure would strand the NFT in escrow, so pit
+    // changes since scheduling must not fail the burn: if the pit switched to
+    // buckets the message is only logged, and an exhausted reward is skipped
+    let stored_message = match pit.storage_mode {
+        PitStorageMode::Records => message.clone(),
+        PitStorageMode::Buckets => None,
+    };
+
+    let token_uri = read_collection_uri(&ctx.accounts.metadata, &config.key())?;
+
+    // Store burned art record
+    let burned_art = &mut ctx.accounts.burned_art;
+    burned_art.artist = burner;
+    burned_art.token_uri = token_uri.clone();
+    burned_art.burned_at = clock.unix_timestamp;
+    burned_art.original_mint = ctx.accounts.mint.key();
+    burned_art.bump = ctx.bumps.burned_art;
+
+    let burn_id = pit.total_burns;
+    record_pit_burn(
+        pit.storage_mode,
+        &mut ctx.accounts.pit_burn_record,
+        ctx.bumps.pit_burn_record,
+        &ctx.accounts.pit_bucket,
+        PitBurn {
+            burner,
+            mint: ctx.accounts.mint.key(),
+            metadata: token_uri,
+            source_collection: Some(config.key()),
+            reason: Some(reason),
+            message: stored_message,
+            timestamp: clock.unix_timestamp,
+            burn_id,
+        },
+    )?;
+
+    // Update burner stats
+    let stats = &mut ctx.accounts.burner_stats;
+    if stats.burner == Pubkey::default() {
+        stats.burner = burner;
+        stats.bump = ctx.bumps.burner_stats;
+    }
+    stats.burn_count = stats.burn_count.checked_add(1).ok_or(BurnError::MathOverflow)?;
+    update_leaderboard(&mut ctx.accounts.burn_leaderboard, burner, stats.burn_count)?;
+    record_season_burn(
+        pit,
+        &mut ctx.accounts.season,
+        &mut ctx.accounts.season_stats,
+        ctx.bumps.season_stats,
+        burner,
+        clock.unix_timestamp,
+    )?;
+
+    pay_pit_burn_reward(
+        &pit.to_account_info(),
+        pit.bump,
+        pit.rewards_enabled,
+        &mut ctx.accounts.pit_rewards,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.burner_reward_account,
+        stats,
+        &ctx.accounts.token_program,
+        clock.unix_timestamp,
+        true,
+    )?;
+
+    // Update counts (checked arithmetic to prevent overflow)
+    config.total_burned = config.total_burned.checked_add(1).ok_or(BurnError::MathOverflow)?;
+    pit.total_burns = pit.total_burns.checked_add(1).ok_or(BurnError::MathOverflow)?;
+
+    // Burn from escrow, signed by the scheduled burn PDA
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"scheduled_burn".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.accounts.scheduled_burn.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.scheduled_burn.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.burner.to_account_info(),
+            authority: ctx.accounts.scheduled_burn.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!("Scheduled burn executed: {}", mint_key);
+    emit!(BurnedToSacredWaste {
+        artist: burner,
+        burn_id,
+        mint: mint_key,
+        reason,
+        message,
+    });
+
+    Ok(())
+}
+
+/// Set how long newly scheduled burns can be cancelled; burns already
+/// scheduled keep their `executes_after`
+pub fn handler_set_cancel_window(ctx: Context<SetCancelWindow>, cancel_window: i64) -> Result<()> {
+    require!(cancel_window >= 0, DelayedBurnError::InvalidCancelWindow);
+
+    ctx.accounts.sacred_waste_pit.cancel_window = cancel_window;
+
+    msg!("Burn cancel window set to {}s", cancel_window);
+
+    Ok(())
+}
+
+#[event]
+pub struct BurnScheduled {
+    pub burner: Pubkey,
+    pub mint: Pubkey,
+    pub executes_after: i64,
+}
+
+#[event]
+pub struct BurnCancelled {
+    pub burner: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[error_code]
+pub enum DelayedBurnError {
+    #[msg("Caller is not the pit authority")]
+    Unauthorized,
+    #[msg("Caller did not schedule this burn")]
+    NotBurner,
+    #[msg("The cancel window for this burn has closed")]
+    CancelWindowClosed,
+    #[msg("The burn cannot execute until its cancel window closes")]
+    CancelWindowOpen,
+    #[msg("Cancel window must not be negative")]
+    InvalidCancelWindow,
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::CollectionConfig;
 
 #[derive(Accounts)]
+#[instruction(slug: String)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -10,7 +11,7 @@ pub struct Initialize<'info> {
         init,
         payer = authority,
         space = CollectionConfig::LEN,
-        seeds = [b"collection_config"],
+        seeds = [b"collection_config", slug.as_bytes()],
         bump
     )]
     pub collection_config: Account<'info, CollectionConfig>,
@@ -20,12 +21,17 @@ pub struct Initialize<'info> {
 
 pub fn handler(
     ctx: Context<Initialize>,
+    slug: String,
     name: String,
     symbol: String,
     uri: String,
     treasury: Pubkey,
     premium_fee: u64,
 ) -> Result<()> {
+    require!(
+        !slug.is_empty() && slug.len() <= CollectionConfig::MAX_SLUG_LEN,
+        ErrorCode::InvalidSlug
+    );
     require!(
         name.len() <= CollectionConfig::MAX_NAME_LEN,
         ErrorCode::NameTooLong
@@ -41,6 +47,7 @@ pub fn handler(
 
     let config = &mut ctx.accounts.collection_config;
     config.authority = ctx.accounts.authority.key();
+    config.slug = slug;
     config.name = name;
     config.symbol = symbol;
     config.uri = uri;
@@ -48,15 +55,23 @@ pub fn handler(
     config.premium_fee = premium_fee;
     config.total_minted = 0;
     config.total_burned = 0;
+    config.backend_signer = None;
+    config.allowed_uri_prefixes = Vec::new();
+    config.sacred_waste_pit = None;
+    config.vrf_oracle = None;
+    config.template_share_bps = 0;
+    config.parent_share_bps = 0;
     config.bump = ctx.bumps.collection_config;
 
-    msg!("Collection initialized: {}", config.name);
+    msg!("Collection initialized: {} ({})", config.name, config.slug);
 
     Ok(())
 }
 
 #[error_code]
 pub enum ErrorCode {
+    #[msg("Collection slug must be 1-32 bytes")]
+    InvalidSlug,
     #[msg("Collection name is too long")]
     NameTooLong,
     #[msg("Collection symbol is too long")]
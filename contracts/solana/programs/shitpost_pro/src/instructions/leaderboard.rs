@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{SacredWastePit, BurnLeaderboard, LeaderboardEntry};
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ LeaderboardError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BurnLeaderboard::LEN,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_init_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.burn_leaderboard;
+    leaderboard.entries = Vec::new();
+    leaderboard.bump = ctx.bumps.burn_leaderboard;
+
+    msg!("Burn leaderboard initialized");
+
+    Ok(())
+}
+
+/// Record `burner`'s new `burn_count` and emit `LeaderboardUpdated` if they
+/// entered the board or changed rank
+pub(crate) fn update_leaderboard(
+    leaderboard: &mut BurnLeaderboard,
+    burner: Pubkey,
+    burn_count: u64,
+) -> Result<()> {
+    if let Some((rank, previous_rank)) = rank_burner(&mut leaderboard.entries, burner, burn_count) {
+        emit!(LeaderboardUpdated {
+            burner,
+            burn_count,
+            rank,
+            previous_rank,
+        });
+    }
+
+    Ok(())
+}
+
+/// Insert or update `burner` in `entries` (sorted highest first, at most
+/// `BurnLeaderboard::MAX_ENTRIES`) and bubble them up into place. Ties keep
+/// the burner who got there first ahead. Returns the new and previous rank
+/// when the ranking changed.
+pub(crate) fn rank_burner(
+    entries: &mut Vec<LeaderboardEntry>,
+    burner: Pubkey,
+    burn_count: u64,
+) -> Option<(u8, Option<u8>)> {
+    let previous_rank = entries.iter().position(|entry| entry.burner == burner);
+
+    let mut rank = match previous_rank {
+        Some(rank) => {
+            entries[rank].burn_count = burn_count;
+            rank
+        }
+        None if entries.len() < BurnLeaderboard::MAX_ENTRIES => {
+            entries.push(LeaderboardEntry { burner, burn_count });
+            entries.len() - 1
+        }
+        None => {
+            let last = entries.len() - 1;
+            if burn_count <= entries[last].burn_count {
+                return None;
+            }
+            entries[last] = LeaderboardEntry { burner, burn_count };
+            last
+        }
+    };
+
+    while rank > 0 && entries[rank - 1].burn_count < burn_count {
+        entries.swap(rank - 1, rank);
+        rank -= 1;
+    }
+
+    (previous_rank != Some(rank)).then(|| (rank as u8, previous_rank.map(|rank| rank as u8)))
+}
+
+/// Ranks are zero-based; `previous_rank` is `None` for a new entrant
+#[event]
+pub struct LeaderboardUpdated {
+    pub burner: Pubkey,
+    pub burn_count: u64,
+    pub rank: u8,
+    pub previous_rank: Option<u8>,
+}
+
+#[error_code]
+pub enum LeaderboardError {
+    #[msg("Caller is not the pit authority")]
+    Unauthorized,
+}
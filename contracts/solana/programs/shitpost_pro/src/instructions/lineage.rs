@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::instructions::templates::clears_rent;
+use crate::state::{Lineage, TokenState};
+
+/// Link a remix to its parent. `parent_lineage` is the parent's `Lineage`
+/// PDA, which is empty when the parent is an original.
+pub(crate) fn record_lineage(
+    lineage: &mut Lineage,
+    bump: u8,
+    mint: Pubkey,
+    parent: &mut TokenState,
+    parent_lineage: &AccountInfo,
+) -> Result<()> {
+    let (root, depth) = if parent_lineage.data_is_empty() {
+        (parent.mint, 1)
+    } else {
+        let data = parent_lineage.try_borrow_data()?;
+        let parent_lineage = Lineage::try_deserialize(&mut &data[..])?;
+        let depth = parent_lineage
+            .depth
+            .checked_add(1)
+            .ok_or(LineageError::MathOverflow)?;
+        (parent_lineage.root, depth)
+    };
+
+    lineage.mint = mint;
+    lineage.parent = parent.mint;
+    lineage.root = root;
+    lineage.depth = depth;
+    lineage.bump = bump;
+
+    parent.remix_count = parent
+        .remix_count
+        .checked_add(1)
+        .ok_or(LineageError::MathOverflow)?;
+
+    msg!("Remix of {} at depth {}", parent.mint, depth);
+    Ok(())
+}
+
+/// Pay the parent's artist `share_bps` of `premium_fee` from the minter.
+/// Returns the share, or zero when it would leave the artist's wallet below
+/// the rent minimum.
+pub(crate) fn pay_parent_artist<'info>(
+    parent: &TokenState,
+    artist: &AccountInfo<'info>,
+    minter: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    premium_fee: u64,
+    share_bps: u16,
+) -> Result<u64> {
+    require_keys_eq!(artist.key(), parent.artist, LineageError::WrongParentArtist);
+
+    let share = (premium_fee as u128 * share_bps as u128 / 10_000) as u64;
+    if share == 0 || !clears_rent(artist, share)? {
+        return Ok(0);
+    }
+
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: minter.clone(),
+                to: artist.clone(),
+            },
+        ),
+        share,
+    )?;
+
+    Ok(share)
+}
+
+#[event]
+pub struct RemixMinted {
+    pub mint: Pubkey,
+    pub parent: Pubkey,
+    pub root: Pubkey,
+    pub depth: u32,
+    pub parent_artist: Pubkey,
+    pub parent_share: u64,
+}
+
+#[error_code]
+pub enum LineageError {
+    #[msg("Parent NFT belongs to another collection")]
+    ParentNotInCollection,
+    #[msg("Artist account does not match the parent NFT's artist")]
+    WrongParentArtist,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
@@ -8,20 +8,29 @@ use mpl_token_metadata::instructions::{
 };
 use mpl_token_metadata::types::DataV2;
 
-use crate::state::CollectionConfig;
+use crate::instructions::vrf::request_randomness;
+use crate::instructions::templates::{pay_template_creator, record_template_use};
+use crate::instructions::lineage::{pay_parent_artist, record_lineage, LineageError, RemixMinted};
+use crate::state::{
+    CollectionConfig, TokenState, ContentHash, VrfRequest, Template, CreatorEarnings, Lineage,
+};
 
 #[derive(Accounts)]
+#[instruction(uri: Option<String>, commitment: Option<[u8; 32]>, content_hash: [u8; 32])]
 pub struct MintNftWithPremium<'info> {
     #[account(mut)]
     pub minter: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"collection_config"],
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
         bump = collection_config.bump
     )]
     pub collection_config: Account<'info, CollectionConfig>,
 
+    /// Backend co-signer, required only when minting with a free-form URI
+    pub backend_signer: Option<Signer<'info>>,
+
     /// CHECK: Treasury receives the premium fee
     #[account(mut, address = collection_config.treasury)]
     pub treasury: UncheckedAccount<'info>,
@@ -43,6 +52,25 @@ pub struct MintNftWithPremium<'info> {
     )]
     pub token_account: Account<'info, TokenAccount>,
 
+    #[account(
+        init,
+        payer = minter,
+        space = TokenState::LEN,
+        seeds = [b"token_state", mint.key().as_ref()],
+        bump
+    )]
+    pub token_state: Box<Account<'info, TokenState>>,
+
+    /// Created on first use of a hash; an existing claim fails the mint
+    #[account(
+        init_if_needed,
+        payer = minter,
+        space = ContentHash::LEN,
+        seeds = [b"content_hash", content_hash.as_ref()],
+        bump
+    )]
+    pub content_hash_record: Box<Account<'info, ContentHash>>,
+
     /// CHECK: Metadata account created via CPI
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
@@ -59,12 +87,172 @@ pub struct MintNftWithPremium<'info> {
     /// CHECK: Metaplex Token Metadata program
     #[account(address = mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// Randomness request, supplied with `vrf_oracle` to roll traits via VRF
+    #[account(
+        init,
+        payer = minter,
+        space = VrfRequest::LEN,
+        seeds = [b"vrf_request", mint.key().as_ref()],
+        bump
+    )]
+    pub vrf_request: Option<Box<Account<'info, VrfRequest>>>,
+
+    /// CHECK: The collection's configured VRF oracle program
+    #[account(
+        executable,
+        constraint = collection_config.vrf_oracle == Some(vrf_oracle.key())
+            @ MintError::InvalidVrfOracle
+    )]
+    pub vrf_oracle: Option<UncheckedAccount<'info>>,
+
+    /// Meme template the art is based on, credited with the mint
+    #[account(
+        mut,
+        seeds = [b"template", template.template_hash.as_ref()],
+        bump = template.bump
+    )]
+    pub template: Option<Box<Account<'info, Template>>>,
+
+    /// CHECK: Template creator, checked against the template; receives its share
+    #[account(mut)]
+    pub template_creator: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = minter,
+        space = CreatorEarnings::LEN,
+        seeds = [b"creator_earnings", template.as_ref().map(|t| t.creator).unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub creator_earnings: Option<Box<Account<'info, CreatorEarnings>>>,
+
+    /// State of the NFT this mint remixes
+    #[account(
+        mut,
+        seeds = [b"token_state", parent_token_state.mint.as_ref()],
+        bump = parent_token_state.bump,
+        constraint = parent_token_state.collection_config == collection_config.key()
+            @ LineageError::ParentNotInCollection
+    )]
+    pub parent_token_state: Option<Box<Account<'info, TokenState>>>,
+
+    /// CHECK: Parent's lineage PDA, empty when the parent is an original
+    #[account(
+        seeds = [b"lineage", parent_token_state.as_ref().map(|p| p.mint).unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub parent_lineage: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = minter,
+        space = Lineage::LEN,
+        seeds = [b"lineage", mint.key().as_ref()],
+        bump
+    )]
+    pub lineage: Option<Box<Account<'info, Lineage>>>,
+
+    /// CHECK: Parent's artist, checked against its token state; receives its share
+    #[account(mut)]
+    pub parent_artist: Option<UncheckedAccount<'info>>,
 }
 
-pub fn handler_with_premium(ctx: Context<MintNftWithPremium>, uri: String) -> Result<()> {
+pub fn handler_with_premium(
+    ctx: Context<MintNftWithPremium>,
+    uri: Option<String>,
+    commitment: Option<[u8; 32]>,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    // init_if_needed so a reused hash fails with a clear error rather than
+    // the system program's "already in use"
+    require!(
+        ctx.accounts.content_hash_record.mint == Pubkey::default(),
+        MintError::DuplicateContent
+    );
+
     let config = &mut ctx.accounts.collection_config;
 
-    // Transfer premium fee to treasury
+    // Free-form URIs must be approved by the backend; otherwise derive from the base URI
+    let token_id = config.total_minted;
+    let uri = match uri {
+        Some(uri) => {
+            let approver = ctx
+                .accounts
+                .backend_signer
+                .as_ref()
+                .ok_or(MintError::BackendApprovalRequired)?;
+            require!(
+                config.backend_signer == Some(approver.key()),
+                MintError::InvalidBackendSigner
+            );
+            validate_uri(config, &uri)?;
+            uri
+        }
+        None => {
+            let uri = format!("{}{}.json", config.uri, token_id);
+            require!(
+                uri.len() <= CollectionConfig::MAX_URI_LEN,
+                MintError::UriTooLong
+            );
+            uri
+        }
+    };
+
+    // Credit the template and route its creator's share of the premium fee
+    let (template, creator_share) = match (
+        &mut ctx.accounts.template,
+        &ctx.accounts.template_creator,
+        &mut ctx.accounts.creator_earnings,
+    ) {
+        (Some(template), Some(creator), Some(earnings)) => {
+            record_template_use(template)?;
+            let share = pay_template_creator(
+                template,
+                &creator.to_account_info(),
+                earnings,
+                ctx.bumps.creator_earnings,
+                &ctx.accounts.minter.to_account_info(),
+                &ctx.accounts.system_program,
+                config.premium_fee,
+                config.template_share_bps,
+            )?;
+            (Some(template.key()), share)
+        }
+        (None, None, None) => (None, 0),
+        _ => return err!(MintError::IncompleteTemplateAccounts),
+    };
+
+    // Link a remix to its parent and route the parent artist's share
+    let (remix, parent_share) = match (
+        &mut ctx.accounts.parent_token_state,
+        &ctx.accounts.parent_lineage,
+        &mut ctx.accounts.lineage,
+        &ctx.accounts.parent_artist,
+    ) {
+        (Some(parent), Some(parent_lineage), Some(lineage), Some(artist)) => {
+            record_lineage(
+                lineage,
+                ctx.bumps.lineage,
+                ctx.accounts.mint.key(),
+                parent,
+                parent_lineage,
+            )?;
+            let share = pay_parent_artist(
+                parent,
+                &artist.to_account_info(),
+                &ctx.accounts.minter.to_account_info(),
+                &ctx.accounts.system_program,
+                config.premium_fee,
+                config.parent_share_bps,
+            )?;
+            (Some((lineage.parent, lineage.root, lineage.depth, artist.key())), share)
+        }
+        (None, None, None, None) => (None, 0),
+        _ => return err!(MintError::IncompleteLineageAccounts),
+    };
+
+    // Transfer the rest of the premium fee to treasury
     transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
@@ -73,14 +261,68 @@ pub fn handler_with_premium(ctx: Context<MintNftWithPremium>, uri: String) -> Re
                 to: ctx.accounts.treasury.to_account_info(),
             },
         ),
-        config.premium_fee,
+        config.premium_fee - creator_share - parent_share,
     )?;
 
-    let token_id = config.total_minted;
     config.total_minted += 1;
 
+    let token_state = &mut ctx.accounts.token_state;
+    token_state.mint = ctx.accounts.mint.key();
+    token_state.collection_config = config.key();
+    token_state.token_id = token_id;
+    token_state.artist = ctx.accounts.minter.key();
+    token_state.remix_count = 0;
+    token_state.content_hash = content_hash;
+    token_state.revealed = false;
+    token_state.bump = ctx.bumps.token_state;
+
+    let claim = &mut ctx.accounts.content_hash_record;
+    claim.content_hash = content_hash;
+    claim.mint = ctx.accounts.mint.key();
+    claim.artist = ctx.accounts.minter.key();
+    claim.created_at = Clock::get()?.unix_timestamp;
+    claim.bump = ctx.bumps.content_hash_record;
+
+    // Backend commits to its trait nonce, bound to the hash of a slot that
+    // has not happened yet when it signs
+    if let Some(commitment) = commitment {
+        let approver = ctx
+            .accounts
+            .backend_signer
+            .as_ref()
+            .ok_or(MintError::BackendApprovalRequired)?;
+        require!(
+            config.backend_signer == Some(approver.key()),
+            MintError::InvalidBackendSigner
+        );
+
+        token_state.commitment = Some(commitment);
+        token_state.commit_slot = Clock::get()?.slot.saturating_add(TokenState::REVEAL_SLOT_DELAY);
+    }
+
+    // Alternatively, ask the VRF oracle; its callback rolls the traits
+    match (&mut ctx.accounts.vrf_request, &ctx.accounts.vrf_oracle) {
+        (Some(vrf_request), Some(vrf_oracle)) => {
+            require!(commitment.is_none(), MintError::ConflictingRandomness);
+            request_randomness(
+                vrf_request,
+                ctx.bumps.vrf_request,
+                &vrf_oracle.to_account_info(),
+                &ctx.accounts.minter.to_account_info(),
+                ctx.accounts.mint.key(),
+                token_id,
+            )?;
+        }
+        (None, None) => {}
+        _ => return err!(MintError::IncompleteVrfAccounts),
+    }
+
     // Mint the token
-    let seeds = &[b"collection_config".as_ref(), &[config.bump]];
+    let seeds = &[
+        b"collection_config".as_ref(),
+        config.slug.as_bytes(),
+        &[config.bump],
+    ];
     let signer_seeds = &[&seeds[..]];
 
     token::mint_to(
@@ -131,12 +373,44 @@ pub fn handler_with_premium(ctx: Context<MintNftWithPremium>, uri: String) -> Re
         token_id,
         artist: ctx.accounts.minter.key(),
         token_uri: uri,
+        template,
+        content_hash,
     });
 
+    if let Some((parent, root, depth, parent_artist)) = remix {
+        emit!(RemixMinted {
+            mint: ctx.accounts.mint.key(),
+            parent,
+            root,
+            depth,
+            parent_artist,
+            parent_share,
+        });
+    }
+
+    Ok(())
+}
+
+/// Check a caller-supplied metadata URI against the Metaplex length cap and
+/// the collection's allowed prefixes, before any lamports move
+pub(crate) fn validate_uri(config: &CollectionConfig, uri: &str) -> Result<()> {
+    require!(!uri.is_empty(), MintError::EmptyUri);
+    require!(
+        uri.len() <= CollectionConfig::MAX_URI_LEN,
+        MintError::UriTooLong
+    );
+    require!(
+        config
+            .allowed_uri_prefixes
+            .iter()
+            .any(|prefix| uri.starts_with(prefix.as_str())),
+        MintError::UriPrefixNotAllowed
+    );
     Ok(())
 }
 
-fn create_metadata_account<'info>(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_metadata_account<'info>(
     metadata: &UncheckedAccount<'info>,
     mint: &AccountInfo<'info>,
     mint_authority: &AccountInfo<'info>,
@@ -185,6 +459,7 @@ fn create_metadata_account<'info>(
             mint_authority.clone(),
             system_program.clone(),
             rent.clone(),
+            token_metadata_program.to_account_info(),
         ],
         signer_seeds,
     )?;
@@ -192,7 +467,8 @@ fn create_metadata_account<'info>(
     Ok(())
 }
 
-fn create_master_edition<'info>(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_master_edition<'info>(
     master_edition: &UncheckedAccount<'info>,
     mint: &AccountInfo<'info>,
     mint_authority: &AccountInfo<'info>,
@@ -234,6 +510,7 @@ fn create_master_edition<'info>(
             token_program.clone(),
             system_program.clone(),
             rent.clone(),
+            token_metadata_program.to_account_info(),
         ],
         signer_seeds,
     )?;
@@ -246,4 +523,32 @@ pub struct ArtMinted {
     pub token_id: u64,
     pub artist: Pubkey,
     pub token_uri: String,
+    pub template: Option<Pubkey>,
+    pub content_hash: [u8; 32],
+}
+
+#[error_code]
+pub enum MintError {
+    #[msg("Free-form URI mints require backend approval")]
+    BackendApprovalRequired,
+    #[msg("Backend signer does not match the collection config")]
+    InvalidBackendSigner,
+    #[msg("Metadata URI is empty")]
+    EmptyUri,
+    #[msg("Metadata URI exceeds the 200 byte Metaplex limit")]
+    UriTooLong,
+    #[msg("Metadata URI does not start with an allowed prefix")]
+    UriPrefixNotAllowed,
+    #[msg("VRF oracle does not match the collection config")]
+    InvalidVrfOracle,
+    #[msg("VRF request and oracle must be supplied together")]
+    IncompleteVrfAccounts,
+    #[msg("Use either a trait commitment or the VRF oracle, not both")]
+    ConflictingRandomness,
+    #[msg("Template, template creator and creator earnings must be supplied together")]
+    IncompleteTemplateAccounts,
+    #[msg("Parent token state, parent lineage, lineage and parent artist must be supplied together")]
+    IncompleteLineageAccounts,
+    #[msg("This content hash has already been minted")]
+    DuplicateContent,
 }
@@ -1,7 +1,31 @@
 pub mod initialize;
 pub mod mint;
+pub mod reveal;
+pub mod vrf;
+pub mod templates;
+pub mod lineage;
+pub mod burn;
+pub mod delayed_burn;
+pub mod pit;
+pub mod rewards;
+pub mod upgrade;
+pub mod leaderboard;
+pub mod seasons;
+pub mod moderation;
 pub mod admin;
 
 pub use initialize::*;
 pub use mint::*;
+pub use reveal::*;
+pub use vrf::*;
+pub use templates::*;
+pub use lineage::*;
+pub use burn::*;
+pub use delayed_burn::*;
+pub use pit::*;
+pub use rewards::*;
+pub use upgrade::*;
+pub use leaderboard::*;
+pub use seasons::*;
+pub use moderation::*;
 pub use admin::*;
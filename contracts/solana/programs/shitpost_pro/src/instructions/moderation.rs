@@ -0,0 +1,604 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::Mint;
+
+use crate::instructions::burn::read_collection_uri;
+use crate::instructions::reveal::set_metadata_uri;
+use crate::state::{
+    CollectionConfig, FrozenNft, Moderator, ModerationAction, ModerationKind, Report,
+};
+
+#[derive(Accounts)]
+#[instruction(moderator: Pubkey)]
+pub struct AddModerator<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ ModerationError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Moderator::LEN,
+        seeds = [b"moderator", collection_config.key().as_ref(), moderator.as_ref()],
+        bump
+    )]
+    pub moderator_record: Account<'info, Moderator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveModerator<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ ModerationError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"moderator",
+            collection_config.key().as_ref(),
+            moderator_record.moderator.as_ref()
+        ],
+        bump = moderator_record.bump
+    )]
+    pub moderator_record: Account<'info, Moderator>,
+}
+
+/// Freeze a collection NFT by taking its content down: the metadata URI is
+/// swapped for the collection's `moderated.json` placeholder, signed by the
+/// config PDA as update authority, and the original is kept for the thaw.
+/// The token account itself is not frozen, as the master edition holds the
+/// freeze authority and a delegated freeze needs the holder's approval, so
+/// the NFT can still be transferred. Burn certificates are rejected.
+#[derive(Accounts)]
+pub struct FreezeNft<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"moderator", collection_config.key().as_ref(), moderator.key().as_ref()],
+        bump = moderator_record.bump
+    )]
+    pub moderator_record: Box<Account<'info, Moderator>>,
+
+    #[account(
+        constraint = mint.freeze_authority != COption::Some(collection_config.key())
+            @ ModerationError::CertificateMint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Metadata PDA of the mint, checked to belong to the collection
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = moderator,
+        space = FrozenNft::LEN,
+        seeds = [b"frozen_nft", mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_nft: Box<Account<'info, FrozenNft>>,
+
+    #[account(
+        init,
+        payer = moderator,
+        space = ModerationAction::LEN,
+        seeds = [
+            b"moderation_action",
+            moderator_record.key().as_ref(),
+            moderator_record.action_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub moderation_action: Box<Account<'info, ModerationAction>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+/// Restore the metadata URI of a frozen NFT and close its takedown record
+#[derive(Accounts)]
+pub struct ThawNft<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"moderator", collection_config.key().as_ref(), moderator.key().as_ref()],
+        bump = moderator_record.bump
+    )]
+    pub moderator_record: Box<Account<'info, Moderator>>,
+
+    /// CHECK: Only used to derive the takedown record and metadata PDAs
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata PDA of the mint, updated via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = moderator,
+        seeds = [b"frozen_nft", mint.key().as_ref()],
+        bump = frozen_nft.bump,
+        constraint = frozen_nft.collection_config == collection_config.key()
+            @ ModerationError::WrongCollection
+    )]
+    pub frozen_nft: Box<Account<'info, FrozenNft>>,
+
+    #[account(
+        init,
+        payer = moderator,
+        space = ModerationAction::LEN,
+        seeds = [
+            b"moderation_action",
+            moderator_record.key().as_ref(),
+            moderator_record.action_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub moderation_action: Box<Account<'info, ModerationAction>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReportNft<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Metadata PDA of the mint, deserialized in the handler
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = Report::LEN,
+        seeds = [b"report", mint.key().as_ref(), reporter.key().as_ref()],
+        bump
+    )]
+    pub report: Box<Account<'info, Report>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resolve a report. Upholding it may also freeze the NFT: supply the
+/// takedown accounts, as for `freeze_nft`.
+#[derive(Accounts)]
+pub struct ResolveReport<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"moderator", collection_config.key().as_ref(), moderator.key().as_ref()],
+        bump = moderator_record.bump
+    )]
+    pub moderator_record: Box<Account<'info, Moderator>>,
+
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [b"report", report.mint.as_ref(), report.reporter.as_ref()],
+        bump = report.bump,
+        constraint = report.collection_config == collection_config.key()
+            @ ModerationError::WrongCollection
+    )]
+    pub report: Box<Account<'info, Report>>,
+
+    /// CHECK: Original reporter, receives the report rent (and bond if upheld)
+    #[account(mut, address = report.reporter)]
+    pub reporter: UncheckedAccount<'info>,
+
+    /// CHECK: Collection treasury, receives slashed bonds
+    #[account(mut, address = collection_config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        address = report.mint @ ModerationError::WrongMint,
+        constraint = mint.freeze_authority != COption::Some(collection_config.key())
+            @ ModerationError::CertificateMint
+    )]
+    pub mint: Option<Account<'info, Mint>>,
+
+    /// CHECK: Metadata PDA of the reported mint, updated via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), report.mint.as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = moderator,
+        space = FrozenNft::LEN,
+        seeds = [b"frozen_nft", report.mint.as_ref()],
+        bump
+    )]
+    pub frozen_nft: Option<Box<Account<'info, FrozenNft>>>,
+
+    #[account(
+        init,
+        payer = moderator,
+        space = ModerationAction::LEN,
+        seeds = [
+            b"moderation_action",
+            moderator_record.key().as_ref(),
+            moderator_record.action_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub moderation_action: Option<Box<Account<'info, ModerationAction>>>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_add_moderator(ctx: Context<AddModerator>, moderator: Pubkey) -> Result<()> {
+    let record = &mut ctx.accounts.moderator_record;
+    record.collection_config = ctx.accounts.collection_config.key();
+    record.moderator = moderator;
+    record.action_count = 0;
+    record.bump = ctx.bumps.moderator_record;
+
+    msg!("Moderator added: {}", moderator);
+    emit!(ModeratorChanged {
+        collection_config: record.collection_config,
+        moderator,
+        is_moderator: true,
+    });
+
+    Ok(())
+}
+
+pub fn handler_remove_moderator(ctx: Context<RemoveModerator>) -> Result<()> {
+    let moderator = ctx.accounts.moderator_record.moderator;
+
+    msg!("Moderator removed: {}", moderator);
+    emit!(ModeratorChanged {
+        collection_config: ctx.accounts.collection_config.key(),
+        moderator,
+        is_moderator: false,
+    });
+
+    Ok(())
+}
+
+pub fn handler_freeze_nft(ctx: Context<FreezeNft>, reason_code: u16) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let config = &accounts.collection_config;
+    read_collection_uri(&accounts.metadata, &config.key())?;
+
+    take_down(
+        config,
+        &accounts.mint.key(),
+        &accounts.metadata,
+        &mut accounts.frozen_nft,
+        ctx.bumps.frozen_nft,
+        &accounts.token_metadata_program,
+    )?;
+
+    accounts.moderation_action.set_inner(ModerationAction {
+        collection_config: config.key(),
+        moderator: accounts.moderator.key(),
+        mint: accounts.mint.key(),
+        kind: ModerationKind::Freeze,
+        reason_code,
+        timestamp: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.moderation_action,
+    });
+    log_moderation_action(&accounts.moderation_action, &mut accounts.moderator_record)
+}
+
+pub fn handler_thaw_nft(ctx: Context<ThawNft>, reason_code: u16) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let config = &accounts.collection_config;
+
+    set_metadata_uri(
+        &accounts.metadata,
+        config,
+        &accounts.token_metadata_program,
+        accounts.frozen_nft.original_uri.clone(),
+    )?;
+
+    accounts.moderation_action.set_inner(ModerationAction {
+        collection_config: config.key(),
+        moderator: accounts.moderator.key(),
+        mint: accounts.mint.key(),
+        kind: ModerationKind::Thaw,
+        reason_code,
+        timestamp: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.moderation_action,
+    });
+    log_moderation_action(&accounts.moderation_action, &mut accounts.moderator_record)
+}
+
+/// Count a recorded action against its moderator and emit it
+fn log_moderation_action(
+    action: &Account<ModerationAction>,
+    moderator_record: &mut Moderator,
+) -> Result<()> {
+    moderator_record.action_count = moderator_record
+        .action_count
+        .checked_add(1)
+        .ok_or(ModerationError::MathOverflow)?;
+
+    msg!("Moderation on {}: reason {}", action.mint, action.reason_code);
+    emit!(NftModerated {
+        moderator: action.moderator,
+        mint: action.mint,
+        kind: action.kind,
+        reason_code: action.reason_code,
+        action: action.key(),
+    });
+
+    Ok(())
+}
+
+/// Swap the metadata URI of `mint` for the collection's moderated
+/// placeholder and keep the original in the (freshly created) takedown record
+fn take_down<'info>(
+    config: &Account<'info, CollectionConfig>,
+    mint: &Pubkey,
+    metadata: &AccountInfo<'info>,
+    frozen_nft: &mut Account<'info, FrozenNft>,
+    frozen_nft_bump: u8,
+    token_metadata_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(frozen_nft.mint == Pubkey::default(), ModerationError::AlreadyFrozen);
+
+    let placeholder = format!("{}moderated.json", config.uri);
+    require!(
+        placeholder.len() <= CollectionConfig::MAX_URI_LEN,
+        ModerationError::UriTooLong
+    );
+    let original_uri = set_metadata_uri(metadata, config, token_metadata_program, placeholder)?;
+
+    frozen_nft.set_inner(FrozenNft {
+        collection_config: config.key(),
+        mint: *mint,
+        original_uri,
+        frozen_at: Clock::get()?.unix_timestamp,
+        bump: frozen_nft_bump,
+    });
+
+    Ok(())
+}
+
+pub fn handler_report_nft(ctx: Context<ReportNft>, reason_code: u16) -> Result<()> {
+    read_collection_uri(&ctx.accounts.metadata, &ctx.accounts.collection_config.key())?;
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reporter.to_account_info(),
+                to: ctx.accounts.report.to_account_info(),
+            },
+        ),
+        Report::BOND_LAMPORTS,
+    )?;
+
+    let report = &mut ctx.accounts.report;
+    report.collection_config = ctx.accounts.collection_config.key();
+    report.mint = ctx.accounts.mint.key();
+    report.reporter = ctx.accounts.reporter.key();
+    report.reason_code = reason_code;
+    report.bond = Report::BOND_LAMPORTS;
+    report.created_at = Clock::get()?.unix_timestamp;
+    report.bump = ctx.bumps.report;
+
+    msg!("NFT reported: {} (reason {})", report.mint, reason_code);
+    emit!(NftReported {
+        mint: report.mint,
+        reporter: report.reporter,
+        reason_code,
+        bond: report.bond,
+    });
+
+    Ok(())
+}
+
+/// Upheld reports refund the bond with the rent and may freeze the NFT;
+/// dismissed reports slash the bond to the treasury and refund only the rent
+pub fn handler_resolve_report(ctx: Context<ResolveReport>, upheld: bool) -> Result<()> {
+    let bond = ctx.accounts.report.bond;
+
+    if !upheld {
+        let report = ctx.accounts.report.to_account_info();
+        let treasury = ctx.accounts.treasury.to_account_info();
+        **report.try_borrow_mut_lamports()? = report
+            .lamports()
+            .checked_sub(bond)
+            .ok_or(ModerationError::MathOverflow)?;
+        **treasury.try_borrow_mut_lamports()? = treasury
+            .lamports()
+            .checked_add(bond)
+            .ok_or(ModerationError::MathOverflow)?;
+    }
+
+    let accounts = &mut *ctx.accounts;
+    let frozen = match (
+        &accounts.mint,
+        &accounts.metadata,
+        &mut accounts.frozen_nft,
+        &mut accounts.moderation_action,
+        &accounts.token_metadata_program,
+    ) {
+        (
+            Some(mint),
+            Some(metadata),
+            Some(frozen_nft),
+            Some(action),
+            Some(token_metadata_program),
+        ) => {
+            require!(upheld, ModerationError::FreezeRequiresUpheld);
+
+            take_down(
+                &accounts.collection_config,
+                &mint.key(),
+                metadata,
+                frozen_nft,
+                ctx.bumps.frozen_nft,
+                token_metadata_program,
+            )?;
+
+            action.set_inner(ModerationAction {
+                collection_config: accounts.collection_config.key(),
+                moderator: accounts.moderator.key(),
+                mint: mint.key(),
+                kind: ModerationKind::Freeze,
+                reason_code: accounts.report.reason_code,
+                timestamp: Clock::get()?.unix_timestamp,
+                bump: ctx.bumps.moderation_action,
+            });
+            log_moderation_action(action, &mut accounts.moderator_record)?;
+            true
+        }
+        (None, None, None, None, None) => false,
+        _ => return err!(ModerationError::IncompleteFreezeAccounts),
+    };
+
+    let report = &accounts.report;
+    msg!("Report on {} {}", report.mint, if upheld { "upheld" } else { "dismissed" });
+    emit!(ReportResolved {
+        mint: report.mint,
+        reporter: report.reporter,
+        moderator: accounts.moderator.key(),
+        upheld,
+        bond,
+        frozen,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ModeratorChanged {
+    pub collection_config: Pubkey,
+    pub moderator: Pubkey,
+    pub is_moderator: bool,
+}
+
+#[event]
+pub struct NftModerated {
+    pub moderator: Pubkey,
+    pub mint: Pubkey,
+    pub kind: ModerationKind,
+    pub reason_code: u16,
+    pub action: Pubkey,
+}
+
+#[event]
+pub struct NftReported {
+    pub mint: Pubkey,
+    pub reporter: Pubkey,
+    pub reason_code: u16,
+    pub bond: u64,
+}
+
+#[event]
+pub struct ReportResolved {
+    pub mint: Pubkey,
+    pub reporter: Pubkey,
+    pub moderator: Pubkey,
+    pub upheld: bool,
+    pub bond: u64,
+    pub frozen: bool,
+}
+
+#[error_code]
+pub enum ModerationError {
+    #[msg("Caller is not the collection authority")]
+    Unauthorized,
+    #[msg("Burn certificates cannot be moderated")]
+    CertificateMint,
+    #[msg("NFT is already frozen")]
+    AlreadyFrozen,
+    #[msg("Moderated placeholder URI exceeds the metadata limit")]
+    UriTooLong,
+    #[msg("Report belongs to another collection")]
+    WrongCollection,
+    #[msg("Mint does not match the report")]
+    WrongMint,
+    #[msg("Only an upheld report can freeze the NFT")]
+    FreezeRequiresUpheld,
+    #[msg("Mint, metadata, takedown record, moderation action and program must be supplied together")]
+    IncompleteFreezeAccounts,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
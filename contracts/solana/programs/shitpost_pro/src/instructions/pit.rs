@@ -0,0 +1,740 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{
+    SacredWastePit, PitBurnRecord, BurnerStats, AuthorizedProgram, AllowedCollection,
+    PitStorageMode, PitBurnBucket, PitBucketEntry, BurnedArt, BurnLeaderboard,
+    Season, SeasonBurnerStats, BurnReason, ScheduledBurn, PitRewards,
+};
+use crate::instructions::burn::validate_burn_message;
+use crate::instructions::leaderboard::update_leaderboard;
+use crate::instructions::rewards::pay_pit_burn_reward;
+use crate::instructions::seasons::record_season_burn;
+
+#[derive(Accounts)]
+pub struct InitializePit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SacredWastePit::LEN,
+        seeds = [b"sacred_waste_pit"],
+        bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit from an authorized program. The caller is identified by a
+/// signature from its `[b"pit_caller"]` PDA rather than by inspecting the
+/// instructions sysvar: the sysvar only lists top-level instructions, so it
+/// cannot tell which program made a CPI, while only the caller program itself
+/// can sign for its PDA.
+#[derive(Accounts)]
+#[instruction(burner: Pubkey, metadata: String)]
+pub struct DepositBurn<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    /// CHECK: The program invoking this instruction, proven by `caller_authority`
+    #[account(executable)]
+    pub caller_program: UncheckedAccount<'info>,
+
+    /// `[b"pit_caller"]` PDA of `caller_program`. Only that program can sign
+    /// for it, so a deposit cannot be made in its name by anyone else.
+    #[account(
+        seeds = [b"pit_caller"],
+        seeds::program = caller_program.key(),
+        bump
+    )]
+    pub caller_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"authorized_program", caller_program.key().as_ref()],
+        bump = authorized_program.bump,
+        constraint = authorized_program.is_authorized @ PitError::Unauthorized
+    )]
+    pub authorized_program: Account<'info, AuthorizedProgram>,
+
+    /// Required in `Records` storage mode
+    #[account(
+        init,
+        payer = payer,
+        space = PitBurnRecord::LEN,
+        seeds = [b"pit_burn", sacred_waste_pit.total_burns.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pit_burn_record: Option<Box<Account<'info, PitBurnRecord>>>,
+
+    /// Current bucket, required in `Buckets` storage mode
+    #[account(mut)]
+    pub pit_bucket: Option<AccountLoader<'info, PitBurnBucket>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BurnerStats::LEN,
+        seeds = [b"burner_stats", burner.as_ref()],
+        bump
+    )]
+    pub burner_stats: Account<'info, BurnerStats>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump = burn_leaderboard.bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+
+    /// Active season, required while a season is open
+    #[account(
+        mut,
+        seeds = [b"season", sacred_waste_pit.current_season.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SeasonBurnerStats::LEN,
+        seeds = [
+            b"season_stats",
+            sacred_waste_pit.current_season.to_le_bytes().as_ref(),
+            burner.as_ref()
+        ],
+        bump
+    )]
+    pub season_stats: Option<Box<Account<'info, SeasonBurnerStats>>>,
+
+    /// Required once pit rewards are initialized
+    #[account(
+        mut,
+        seeds = [b"pit_rewards"],
+        bump = pit_rewards.bump
+    )]
+    pub pit_rewards: Option<Box<Account<'info, PitRewards>>>,
+
+    /// Reward vault, checked against `pit_rewards.vault`
+    #[account(mut)]
+    pub reward_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Burner's reward token account, checked against the reward mint
+    #[account(mut)]
+    pub burner_reward_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AuthorizeProgram<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    /// CHECK: The program to authorize
+    #[account(
+        executable,
+        constraint = program_to_authorize.key() != crate::ID @ PitError::CannotAuthorizeSelf
+    )]
+    pub program_to_authorize: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AuthorizedProgram::LEN,
+        seeds = [b"authorized_program", program_to_authorize.key().as_ref()],
+        bump
+    )]
+    pub authorized_program: Account<'info, AuthorizedProgram>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeProgram<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"authorized_program", authorized_program.program_id.as_ref()],
+        bump = authorized_program.bump
+    )]
+    pub authorized_program: Account<'info, AuthorizedProgram>,
+}
+
+#[derive(Accounts)]
+pub struct AllowCollection<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    /// CHECK: Metaplex collection mint to allow, only used as a seed
+    pub collection_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AllowedCollection::LEN,
+        seeds = [b"allowed_collection", collection_mint.key().as_ref()],
+        bump
+    )]
+    pub allowed_collection: Account<'info, AllowedCollection>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DisallowCollection<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"allowed_collection", allowed_collection.collection_mint.as_ref()],
+        bump = allowed_collection.bump
+    )]
+    pub allowed_collection: Account<'info, AllowedCollection>,
+}
+
+#[derive(Accounts)]
+pub struct SetPitStorageMode<'info> {
+    #[account(
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+}
+
+#[derive(Accounts)]
+#[instruction(bucket_index: u64)]
+pub struct OpenPitBucket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump,
+        constraint = bucket_index == sacred_waste_pit.total_burns / PitBurnBucket::CAPACITY
+            @ PitError::WrongBucket
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PitBurnBucket::LEN,
+        seeds = [b"pit_bucket", bucket_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pit_bucket: AccountLoader<'info, PitBurnBucket>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePitBucket<'info> {
+    #[account(
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(mut, close = payer)]
+    pub pit_bucket: AccountLoader<'info, PitBurnBucket>,
+
+    /// CHECK: Original rent payer, checked against the bucket
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRecordArchival<'info> {
+    #[account(
+        constraint = authority.key() == sacred_waste_pit.authority @ PitError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBurnRecord<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump,
+        constraint = sacred_waste_pit.record_closing_enabled @ PitError::RecordClosingDisabled
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        mut,
+        close = burner,
+        constraint = burned_art.artist == burner.key() @ PitError::NotRecordOwner
+    )]
+    pub burned_art: Option<Box<Account<'info, BurnedArt>>>,
+
+    #[account(
+        mut,
+        close = burner,
+        constraint = pit_burn_record.burner == burner.key() @ PitError::NotRecordOwner
+    )]
+    pub pit_burn_record: Option<Box<Account<'info, PitBurnRecord>>>,
+}
+
+pub fn handler_init(ctx: Context<InitializePit>) -> Result<()> {
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    pit.authority = ctx.accounts.authority.key();
+    pit.total_burns = 0;
+    pit.storage_mode = PitStorageMode::Records;
+    pit.record_closing_enabled = false;
+    pit.archival_period = 0;
+    pit.current_season = 0;
+    pit.season_active = false;
+    pit.rewards_enabled = false;
+    pit.cancel_window = ScheduledBurn::DEFAULT_CANCEL_WINDOW;
+    pit.bump = ctx.bumps.sacred_waste_pit;
+
+    msg!("Sacred Waste Pit initialized");
+
+    Ok(())
+}
+
+pub fn handler_deposit(
+    ctx: Context<DepositBurn>,
+    burner: Pubkey,
+    metadata: String,
+    reason: Option<BurnReason>,
+    message: Option<String>,
+) -> Result<()> {
+    require!(
+        metadata.len() <= PitBurnRecord::MAX_METADATA_LEN,
+        PitError::MetadataTooLong
+    );
+    validate_burn_message(&message)?;
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    let clock = Clock::get()?;
+
+    let burn_id = pit.total_burns;
+    record_pit_burn(
+        pit.storage_mode,
+        &mut ctx.accounts.pit_burn_record,
+        ctx.bumps.pit_burn_record,
+        &ctx.accounts.pit_bucket,
+        PitBurn {
+            burner,
+            mint: Pubkey::default(),
+            metadata,
+            source_collection: None,
+            reason,
+            message: message.clone(),
+            timestamp: clock.unix_timestamp,
+            burn_id,
+        },
+    )?;
+
+    // Update burner stats
+    let stats = &mut ctx.accounts.burner_stats;
+    if stats.burner == Pubkey::default() {
+        stats.burner = burner;
+        stats.bump = ctx.bumps.burner_stats;
+    }
+    stats.burn_count = stats.burn_count.checked_add(1).ok_or(PitError::MathOverflow)?;
+    update_leaderboard(&mut ctx.accounts.burn_leaderboard, burner, stats.burn_count)?;
+    record_season_burn(
+        pit,
+        &mut ctx.accounts.season,
+        &mut ctx.accounts.season_stats,
+        ctx.bumps.season_stats,
+        burner,
+        clock.unix_timestamp,
+    )?;
+
+    pay_pit_burn_reward(
+        &pit.to_account_info(),
+        pit.bump,
+        pit.rewards_enabled,
+        &mut ctx.accounts.pit_rewards,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.burner_reward_account,
+        stats,
+        &ctx.accounts.token_program,
+        clock.unix_timestamp,
+        false,
+    )?;
+
+    // Increment total burns (checked arithmetic to prevent overflow)
+    pit.total_burns = pit.total_burns.checked_add(1).ok_or(PitError::MathOverflow)?;
+
+    msg!("Burn deposited to pit: burn_id={}", burn_id);
+    emit!(BurnDeposited {
+        burn_id,
+        burner,
+        caller_program: ctx.accounts.caller_program.key(),
+        reason,
+        message,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handler_authorize(ctx: Context<AuthorizeProgram>) -> Result<()> {
+    let auth = &mut ctx.accounts.authorized_program;
+    auth.program_id = ctx.accounts.program_to_authorize.key();
+    auth.is_authorized = true;
+    auth.bump = ctx.bumps.authorized_program;
+
+    msg!("Program {} authorized", auth.program_id);
+    emit!(ProgramAuthorizationChanged {
+        program_id: auth.program_id,
+        is_authorized: true,
+    });
+
+    Ok(())
+}
+
+pub fn handler_revoke(ctx: Context<RevokeProgram>) -> Result<()> {
+    let program_id = ctx.accounts.authorized_program.program_id;
+
+    msg!("Program {} revoked", program_id);
+    emit!(ProgramAuthorizationChanged {
+        program_id,
+        is_authorized: false,
+    });
+
+    Ok(())
+}
+
+pub fn handler_allow_collection(ctx: Context<AllowCollection>) -> Result<()> {
+    let allowed = &mut ctx.accounts.allowed_collection;
+    allowed.collection_mint = ctx.accounts.collection_mint.key();
+    allowed.bump = ctx.bumps.allowed_collection;
+
+    msg!("Collection {} allowed into the pit", allowed.collection_mint);
+    emit!(CollectionAllowlistChanged {
+        collection_mint: allowed.collection_mint,
+        is_allowed: true,
+    });
+
+    Ok(())
+}
+
+pub fn handler_disallow_collection(ctx: Context<DisallowCollection>) -> Result<()> {
+    let collection_mint = ctx.accounts.allowed_collection.collection_mint;
+
+    msg!("Collection {} removed from the pit allowlist", collection_mint);
+    emit!(CollectionAllowlistChanged {
+        collection_mint,
+        is_allowed: false,
+    });
+
+    Ok(())
+}
+
+pub fn handler_set_storage_mode(
+    ctx: Context<SetPitStorageMode>,
+    storage_mode: PitStorageMode,
+) -> Result<()> {
+    ctx.accounts.sacred_waste_pit.storage_mode = storage_mode;
+
+    msg!("Pit storage mode updated");
+
+    Ok(())
+}
+
+pub fn handler_set_record_archival(
+    ctx: Context<SetRecordArchival>,
+    enabled: bool,
+    archival_period: i64,
+) -> Result<()> {
+    require!(archival_period >= 0, PitError::InvalidArchivalPeriod);
+
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    pit.record_closing_enabled = enabled;
+    pit.archival_period = archival_period;
+
+    msg!("Burn record closing enabled: {}, archival period {}s", enabled, archival_period);
+
+    Ok(())
+}
+
+/// Close a burner's `BurnedArt` and/or `PitBurnRecord` once the archival
+/// period has passed. The full record is emitted first so indexers keep it.
+pub fn handler_close_burn_record(ctx: Context<CloseBurnRecord>) -> Result<()> {
+    let archival_period = ctx.accounts.sacred_waste_pit.archival_period;
+    let now = Clock::get()?.unix_timestamp;
+    let archived = |timestamp: i64| now >= timestamp.saturating_add(archival_period);
+
+    require!(
+        ctx.accounts.burned_art.is_some() || ctx.accounts.pit_burn_record.is_some(),
+        PitError::NoRecordToClose
+    );
+
+    if let Some(burned_art) = &ctx.accounts.burned_art {
+        require!(archived(burned_art.burned_at), PitError::ArchivalPeriodNotElapsed);
+        emit!(BurnedArtArchived {
+            artist: burned_art.artist,
+            token_uri: burned_art.token_uri.clone(),
+            burned_at: burned_art.burned_at,
+            original_mint: burned_art.original_mint,
+            closed_at: now,
+        });
+    }
+
+    if let Some(record) = &ctx.accounts.pit_burn_record {
+        require!(archived(record.timestamp), PitError::ArchivalPeriodNotElapsed);
+        emit!(PitBurnRecordArchived {
+            burner: record.burner,
+            metadata: record.metadata.clone(),
+            timestamp: record.timestamp,
+            burn_id: record.burn_id,
+            source_collection: record.source_collection,
+            reason: record.reason,
+            message: record.message.clone(),
+            closed_at: now,
+        });
+    }
+
+    msg!("Burn records closed for {}", ctx.accounts.burner.key());
+
+    Ok(())
+}
+
+pub fn handler_open_bucket(ctx: Context<OpenPitBucket>, bucket_index: u64) -> Result<()> {
+    let mut bucket = ctx.accounts.pit_bucket.load_init()?;
+    bucket.bucket_index = bucket_index;
+    bucket.len = 0;
+    bucket.payer = ctx.accounts.payer.key();
+
+    msg!("Pit bucket {} opened", bucket_index);
+
+    Ok(())
+}
+
+pub fn handler_close_bucket(ctx: Context<ClosePitBucket>) -> Result<()> {
+    let bucket = ctx.accounts.pit_bucket.load()?;
+    require_keys_eq!(
+        ctx.accounts.payer.key(),
+        bucket.payer,
+        PitError::WrongBucketPayer
+    );
+    // Only buckets that burns have moved past can be closed
+    require!(
+        ctx.accounts.sacred_waste_pit.total_burns / PitBurnBucket::CAPACITY > bucket.bucket_index,
+        PitError::BucketStillActive
+    );
+
+    msg!("Pit bucket {} closed", bucket.bucket_index);
+    emit!(PitBucketClosed {
+        bucket_index: bucket.bucket_index,
+    });
+
+    Ok(())
+}
+
+/// A burn to be written to pit storage
+pub(crate) struct PitBurn {
+    pub burner: Pubkey,
+    pub mint: Pubkey,
+    pub metadata: String,
+    pub source_collection: Option<Pubkey>,
+    pub reason: Option<BurnReason>,
+    pub message: Option<String>,
+    pub timestamp: i64,
+    pub burn_id: u64,
+}
+
+/// Write a burn to a `PitBurnRecord` or append it to the current
+/// `PitBurnBucket`, depending on the pit's storage mode. Bucket entries keep
+/// the fixed-size fields, the source collection and the reason but drop the
+/// metadata URI, so messages are rejected rather than silently discarded.
+pub(crate) fn record_pit_burn(
+    storage_mode: PitStorageMode,
+    record: &mut Option<Box<Account<PitBurnRecord>>>,
+    record_bump: u8,
+    bucket: &Option<AccountLoader<PitBurnBucket>>,
+    burn: PitBurn,
+) -> Result<()> {
+    match (storage_mode, record, bucket) {
+        (PitStorageMode::Records, Some(record), None) => {
+            record.burner = burn.burner;
+            record.metadata = burn.metadata;
+            record.timestamp = burn.timestamp;
+            record.burn_id = burn.burn_id;
+            record.source_collection = burn.source_collection;
+            record.reason = burn.reason;
+            record.message = burn.message;
+            record.bump = record_bump;
+        }
+        (PitStorageMode::Buckets, None, Some(bucket)) => {
+            let mut bucket = bucket.load_mut()?;
+            require!(
+                bucket.bucket_index == burn.burn_id / PitBurnBucket::CAPACITY,
+                PitError::WrongBucket
+            );
+            require!(burn.message.is_none(), PitError::MessageNotStored);
+            // Slot by burn ID so a mid-bucket switch from `Records` keeps IDs aligned
+            let slot = (burn.burn_id % PitBurnBucket::CAPACITY) as usize;
+            bucket.entries[slot] = PitBucketEntry {
+                burner: burn.burner,
+                mint: burn.mint,
+                source_collection: burn.source_collection.unwrap_or_default(),
+                burn_id: burn.burn_id,
+                timestamp: burn.timestamp,
+                reason: burn.reason.map_or(0, |reason| reason as u8 + 1),
+                _padding: [0; 7],
+            };
+            bucket.len += 1;
+        }
+        _ => return err!(PitError::WrongStorageAccounts),
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct BurnDeposited {
+    pub burn_id: u64,
+    pub burner: Pubkey,
+    pub caller_program: Pubkey,
+    pub reason: Option<BurnReason>,
+    pub message: Option<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProgramAuthorizationChanged {
+    pub program_id: Pubkey,
+    pub is_authorized: bool,
+}
+
+#[event]
+pub struct PitBucketClosed {
+    pub bucket_index: u64,
+}
+
+#[event]
+pub struct BurnedArtArchived {
+    pub artist: Pubkey,
+    pub token_uri: String,
+    pub burned_at: i64,
+    pub original_mint: Pubkey,
+    pub closed_at: i64,
+}
+
+#[event]
+pub struct PitBurnRecordArchived {
+    pub burner: Pubkey,
+    pub metadata: String,
+    pub timestamp: i64,
+    pub burn_id: u64,
+    pub source_collection: Option<Pubkey>,
+    pub reason: Option<BurnReason>,
+    pub message: Option<String>,
+    pub closed_at: i64,
+}
+
+#[event]
+pub struct CollectionAllowlistChanged {
+    pub collection_mint: Pubkey,
+    pub is_allowed: bool,
+}
+
+#[error_code]
+pub enum PitError {
+    #[msg("Caller is not authorized")]
+    Unauthorized,
+    #[msg("Metadata is too long")]
+    MetadataTooLong,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("The pit program cannot authorize itself")]
+    CannotAuthorizeSelf,
+    #[msg("Storage accounts do not match the pit storage mode")]
+    WrongStorageAccounts,
+    #[msg("Bucket is not the pit's current bucket")]
+    WrongBucket,
+    #[msg("Payer does not match the bucket's rent payer")]
+    WrongBucketPayer,
+    #[msg("Only buckets behind the current one can be closed")]
+    BucketStillActive,
+    #[msg("Archival period must not be negative")]
+    InvalidArchivalPeriod,
+    #[msg("Closing burn records is disabled")]
+    RecordClosingDisabled,
+    #[msg("No burn record supplied to close")]
+    NoRecordToClose,
+    #[msg("Burn record belongs to another burner")]
+    NotRecordOwner,
+    #[msg("Burn record is still within its archival period")]
+    ArchivalPeriodNotElapsed,
+    #[msg("Burn messages are not stored in bucket storage mode")]
+    MessageNotStored,
+}
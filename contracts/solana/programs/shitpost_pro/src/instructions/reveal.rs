@@ -0,0 +1,438 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::instructions::{
+    UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs,
+};
+use mpl_token_metadata::types::DataV2;
+
+use crate::instructions::mint::validate_uri;
+use crate::state::{
+    CollectionConfig, TokenState, TokenTraits, TokenTier, TraitTable, CardTraits, Aura, CardTier,
+    CardClass,
+};
+
+#[derive(Accounts)]
+pub struct Reveal<'info> {
+    #[account(
+        constraint = collection_config.backend_signer == Some(backend_signer.key())
+            @ RevealError::InvalidBackendSigner
+    )]
+    pub backend_signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    /// CHECK: Only used to derive the token state and metadata PDAs; the
+    /// `token_state` seeds and constraint tie it to the revealed token
+    #[account(constraint = mint.key() == token_state.mint @ RevealError::WrongMint)]
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"token_state", mint.key().as_ref()],
+        bump = token_state.bump,
+        constraint = token_state.collection_config == collection_config.key()
+            @ RevealError::WrongCollection,
+        constraint = !token_state.revealed @ RevealError::AlreadyRevealed
+    )]
+    pub token_state: Account<'info, TokenState>,
+
+    /// CHECK: Metadata PDA of the mint, updated via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Takedown record of the mint; revealing would undo a freeze,
+    /// so it must not exist
+    #[account(
+        seeds = [b"frozen_nft", mint.key().as_ref()],
+        bump,
+        constraint = frozen_nft.data_is_empty() @ RevealError::Frozen
+    )]
+    pub frozen_nft: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealTraits<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Only used to derive the token PDAs
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"token_state", mint.key().as_ref()],
+        bump = token_state.bump
+    )]
+    pub token_state: Account<'info, TokenState>,
+
+    #[account(
+        seeds = [b"trait_table", token_state.collection_config.as_ref()],
+        bump = trait_table.bump
+    )]
+    pub trait_table: Account<'info, TraitTable>,
+
+    /// Created once, so traits can only be rolled a single time
+    #[account(
+        init,
+        payer = payer,
+        space = TokenTraits::LEN,
+        seeds = [b"token_traits", mint.key().as_ref()],
+        bump
+    )]
+    pub token_traits: Account<'info, TokenTraits>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = TokenTier::LEN,
+        seeds = [b"token_tier", mint.key().as_ref()],
+        bump
+    )]
+    pub token_tier: Account<'info, TokenTier>,
+
+    /// CHECK: SlotHashes sysvar, read for the hash of the commit slot
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTraitTable<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ RevealError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TraitTable::LEN,
+        seeds = [b"trait_table", collection_config.key().as_ref()],
+        bump
+    )]
+    pub trait_table: Account<'info, TraitTable>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetTokenTraits<'info> {
+    /// CHECK: Only used to derive the token traits PDA
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"token_traits", mint.key().as_ref()],
+        bump = token_traits.bump
+    )]
+    pub token_traits: Account<'info, TokenTraits>,
+}
+
+/// Replace the placeholder metadata URI of a freshly minted token, once
+pub fn handler_reveal(ctx: Context<Reveal>, uri: String) -> Result<()> {
+    let config = &ctx.accounts.collection_config;
+    validate_uri(config, &uri)?;
+
+    set_metadata_uri(
+        &ctx.accounts.metadata,
+        config,
+        &ctx.accounts.token_metadata_program,
+        uri.clone(),
+    )?;
+
+    let token_state = &mut ctx.accounts.token_state;
+    token_state.revealed = true;
+
+    msg!("Token #{} revealed", token_state.token_id);
+    emit!(TokenRevealed {
+        mint: token_state.mint,
+        token_id: token_state.token_id,
+        uri,
+    });
+
+    Ok(())
+}
+
+/// Point the metadata of a collection NFT at `uri`, signing as the config
+/// PDA in its role as update authority. Returns the URI it replaced.
+pub(crate) fn set_metadata_uri<'info>(
+    metadata: &AccountInfo<'info>,
+    config: &Account<'info, CollectionConfig>,
+    token_metadata_program: &AccountInfo<'info>,
+    uri: String,
+) -> Result<String> {
+    let current = {
+        let data = metadata.try_borrow_data()?;
+        Metadata::safe_deserialize(&data).map_err(|_| RevealError::InvalidMetadata)?
+    };
+    let replaced = current.uri.trim_end_matches('\0').to_string();
+
+    let accounts = UpdateMetadataAccountV2 {
+        metadata: metadata.key(),
+        update_authority: config.key(),
+    };
+    let args = UpdateMetadataAccountV2InstructionArgs {
+        data: Some(DataV2 {
+            name: current.name.trim_end_matches('\0').to_string(),
+            symbol: current.symbol.trim_end_matches('\0').to_string(),
+            uri,
+            seller_fee_basis_points: current.seller_fee_basis_points,
+            creators: current.creators,
+            collection: current.collection,
+            uses: current.uses,
+        }),
+        new_update_authority: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    };
+
+    let seeds = &[
+        b"collection_config".as_ref(),
+        config.slug.as_bytes(),
+        &[config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &accounts.instruction(args),
+        &[
+            metadata.clone(),
+            config.to_account_info(),
+            token_metadata_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(replaced)
+}
+
+/// Anyone holding the nonce can reveal: the seed is
+/// `sha256(slot_hash || nonce || mint)` over the hash of the first block at or
+/// after the commit slot. That slot lies in the future when the backend signs
+/// the mint, so neither the backend (fixed by its commitment) nor the minter
+/// can predict the outcome. SlotHashes only reaches back ~512 slots, so the
+/// reveal must land within a few minutes of the commit slot.
+pub fn handler_reveal_traits(ctx: Context<RevealTraits>, nonce: [u8; 32]) -> Result<()> {
+    let token_state = &ctx.accounts.token_state;
+    let commitment = token_state.commitment.ok_or(RevealError::NoCommitment)?;
+    require!(
+        hash(&nonce).to_bytes() == commitment,
+        RevealError::CommitmentMismatch
+    );
+
+    let slot_hash = commit_slot_hash(&ctx.accounts.slot_hashes, token_state.commit_slot)?;
+    let seed = hashv(&[&slot_hash, &nonce, token_state.mint.as_ref()]).to_bytes();
+    write_traits(
+        &mut ctx.accounts.token_traits,
+        ctx.bumps.token_traits,
+        &mut ctx.accounts.token_tier,
+        ctx.bumps.token_tier,
+        token_state,
+        &ctx.accounts.trait_table,
+        seed,
+    );
+
+    Ok(())
+}
+
+/// Hash of the first block at or after `commit_slot`, read from the SlotHashes
+/// sysvar without deserializing the whole (large) account: a u64 length
+/// followed by `(slot, hash)` pairs, newest first. Skipped slots have no
+/// entry, so the match is only trusted once an older slot shows up after it.
+fn commit_slot_hash(slot_hashes: &AccountInfo, commit_slot: u64) -> Result<[u8; 32]> {
+    const ENTRY_LEN: usize = 8 + 32;
+
+    let data = slot_hashes.try_borrow_data()?;
+    let len = data
+        .get(0..8)
+        .map_or(0, |len| u64::from_le_bytes(len.try_into().unwrap())) as usize;
+
+    let mut found = None;
+    for entry in data[8..].chunks_exact(ENTRY_LEN).take(len) {
+        let slot = u64::from_le_bytes(entry[0..8].try_into().unwrap());
+        if slot < commit_slot {
+            return found.ok_or_else(|| error!(RevealError::CommitSlotNotReached));
+        }
+        found = Some(entry[8..ENTRY_LEN].try_into().unwrap());
+    }
+
+    // Every remembered slot is at or after the commit slot
+    err!(RevealError::CommitSlotExpired)
+}
+
+/// Roll traits from `seed` against the collection's trait table and store
+/// them in the token's freshly created `TokenTraits` and `TokenTier`
+pub(crate) fn write_traits(
+    token_traits: &mut TokenTraits,
+    traits_bump: u8,
+    token_tier: &mut TokenTier,
+    tier_bump: u8,
+    token_state: &TokenState,
+    table: &TraitTable,
+    seed: [u8; 32],
+) {
+    let traits = CardTraits {
+        aura: Aura::ALL[roll(&seed[0..4], &table.aura_weights)],
+        class: CardClass::ALL[roll(&seed[8..12], &table.class_weights)],
+    };
+    let tier = CardTier::ALL[roll(&seed[4..8], &table.tier_weights)];
+
+    token_traits.mint = token_state.mint;
+    token_traits.token_id = token_state.token_id;
+    token_traits.traits = traits;
+    token_traits.bump = traits_bump;
+
+    token_tier.mint = token_state.mint;
+    token_tier.tier = tier;
+    token_tier.bump = tier_bump;
+
+    msg!("Token #{} traits revealed", token_state.token_id);
+    emit!(TraitsRevealed {
+        mint: token_state.mint,
+        token_id: token_state.token_id,
+        seed,
+        traits,
+        tier,
+        trait_table_hash: table.weights_hash(),
+    });
+}
+
+/// Set the collection's trait weights. A collection without a table may set
+/// one once at any time, so tokens minted before it can still reveal; an
+/// existing table can change until the drop starts: before the first mint
+/// and before its `drop_starts_at`.
+pub fn handler_set_trait_table(
+    ctx: Context<SetTraitTable>,
+    aura_weights: [u16; 5],
+    tier_weights: [u16; 5],
+    class_weights: [u16; 5],
+    drop_starts_at: i64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.trait_table;
+    if table.collection_config != Pubkey::default() {
+        require!(
+            ctx.accounts.collection_config.total_minted == 0
+                && Clock::get()?.unix_timestamp < table.drop_starts_at,
+            RevealError::TraitTableLocked
+        );
+    }
+    for weights in [&aura_weights, &tier_weights, &class_weights] {
+        require!(weights.iter().any(|&weight| weight > 0), RevealError::EmptyTraitWeights);
+    }
+
+    table.collection_config = ctx.accounts.collection_config.key();
+    table.aura_weights = aura_weights;
+    table.tier_weights = tier_weights;
+    table.class_weights = class_weights;
+    table.drop_starts_at = drop_starts_at;
+    table.bump = ctx.bumps.trait_table;
+
+    let weights_hash = table.weights_hash();
+    msg!("Trait table set, locks at {}", drop_starts_at);
+    emit!(TraitTableSet {
+        collection_config: table.collection_config,
+        aura_weights,
+        tier_weights,
+        class_weights,
+        drop_starts_at,
+        weights_hash,
+    });
+
+    Ok(())
+}
+
+/// Return a revealed token's traits; CPI callers read them from the return data
+pub fn handler_get_token_traits(ctx: Context<GetTokenTraits>) -> Result<CardTraits> {
+    Ok(ctx.accounts.token_traits.traits)
+}
+
+/// Pick an index from `weights` using four seed bytes
+fn roll(bytes: &[u8], weights: &[u16]) -> usize {
+    let total: u32 = weights.iter().map(|&weight| weight as u32).sum();
+    let mut point = u32::from_le_bytes(bytes.try_into().unwrap()) % total;
+    for (index, &weight) in weights.iter().enumerate() {
+        if point < weight as u32 {
+            return index;
+        }
+        point -= weight as u32;
+    }
+    weights.len() - 1
+}
+
+#[event]
+pub struct TraitsRevealed {
+    pub mint: Pubkey,
+    pub token_id: u64,
+    pub seed: [u8; 32],
+    pub traits: CardTraits,
+    pub tier: CardTier,
+    pub trait_table_hash: [u8; 32],
+}
+
+#[event]
+pub struct TraitTableSet {
+    pub collection_config: Pubkey,
+    pub aura_weights: [u16; 5],
+    pub tier_weights: [u16; 5],
+    pub class_weights: [u16; 5],
+    pub drop_starts_at: i64,
+    pub weights_hash: [u8; 32],
+}
+
+#[event]
+pub struct TokenRevealed {
+    pub mint: Pubkey,
+    pub token_id: u64,
+    pub uri: String,
+}
+
+#[error_code]
+pub enum RevealError {
+    #[msg("Caller is not the collection authority")]
+    Unauthorized,
+    #[msg("Trait table is locked once the drop starts")]
+    TraitTableLocked,
+    #[msg("Each trait category needs a non-zero weight")]
+    EmptyTraitWeights,
+    #[msg("Backend signer does not match the collection config")]
+    InvalidBackendSigner,
+    #[msg("Token belongs to another collection")]
+    WrongCollection,
+    #[msg("Mint does not match the token state")]
+    WrongMint,
+    #[msg("Token has already been revealed")]
+    AlreadyRevealed,
+    #[msg("Metadata account could not be deserialized")]
+    InvalidMetadata,
+    #[msg("Token was minted without a trait commitment")]
+    NoCommitment,
+    #[msg("Nonce does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Token has been frozen by a moderator")]
+    Frozen,
+    #[msg("Commit slot has not been reached yet")]
+    CommitSlotNotReached,
+    #[msg("Commit slot hash is no longer in the SlotHashes sysvar")]
+    CommitSlotExpired,
+}
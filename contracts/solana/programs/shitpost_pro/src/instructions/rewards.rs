@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::{SacredWastePit, PitRewards, RewardTier, BurnerStats};
+
+#[derive(Accounts)]
+pub struct InitializeRewards<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ RewardError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PitRewards::LEN,
+        seeds = [b"pit_rewards"],
+        bump
+    )]
+    pub pit_rewards: Account<'info, PitRewards>,
+
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = reward_mint,
+        token::authority = sacred_waste_pit,
+        seeds = [b"reward_vault"],
+        bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetRewardConfig<'info> {
+    #[account(
+        constraint = authority.key() == sacred_waste_pit.authority @ RewardError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        mut,
+        seeds = [b"pit_rewards"],
+        bump = pit_rewards.bump
+    )]
+    pub pit_rewards: Account<'info, PitRewards>,
+}
+
+pub fn handler_init_rewards(
+    ctx: Context<InitializeRewards>,
+    reward_per_burn: u64,
+    epoch_duration: i64,
+    epoch_cap: u64,
+) -> Result<()> {
+    require!(epoch_duration > 0, RewardError::InvalidEpochDuration);
+
+    let rewards = &mut ctx.accounts.pit_rewards;
+    rewards.reward_mint = ctx.accounts.reward_mint.key();
+    rewards.vault = ctx.accounts.reward_vault.key();
+    rewards.reward_per_burn = reward_per_burn;
+    rewards.tiers = Vec::new();
+    rewards.epoch_duration = epoch_duration;
+    rewards.epoch_cap = epoch_cap;
+    rewards.epoch_start = Clock::get()?.unix_timestamp;
+    rewards.epoch_emitted = 0;
+    rewards.total_emitted = 0;
+    rewards.vault_bump = ctx.bumps.reward_vault;
+    rewards.bump = ctx.bumps.pit_rewards;
+    ctx.accounts.sacred_waste_pit.rewards_enabled = true;
+
+    msg!("Pit rewards initialized: {} per burn", reward_per_burn);
+
+    Ok(())
+}
+
+pub fn handler_set_reward_config(
+    ctx: Context<SetRewardConfig>,
+    reward_per_burn: u64,
+    epoch_duration: i64,
+    epoch_cap: u64,
+) -> Result<()> {
+    require!(epoch_duration > 0, RewardError::InvalidEpochDuration);
+
+    let rewards = &mut ctx.accounts.pit_rewards;
+    rewards.reward_per_burn = reward_per_burn;
+    rewards.epoch_duration = epoch_duration;
+    rewards.epoch_cap = epoch_cap;
+
+    msg!("Pit rewards updated: {} per burn, cap {} per epoch", reward_per_burn, epoch_cap);
+    emit!(RewardConfigUpdated {
+        reward_per_burn,
+        epoch_duration,
+        epoch_cap,
+    });
+
+    Ok(())
+}
+
+pub fn handler_set_reward_tiers(
+    ctx: Context<SetRewardConfig>,
+    tiers: Vec<RewardTier>,
+) -> Result<()> {
+    require!(tiers.len() <= PitRewards::MAX_TIERS, RewardError::TooManyTiers);
+    require!(
+        tiers
+            .windows(2)
+            .all(|pair| pair[0].min_burn_count < pair[1].min_burn_count),
+        RewardError::TiersNotAscending
+    );
+
+    ctx.accounts.pit_rewards.tiers = tiers;
+
+    msg!("Pit reward tiers updated");
+
+    Ok(())
+}
+
+/// Pay the burn reward. The reward accounts are required once pit rewards are
+/// initialized and must be omitted before that. With `skip_when_exhausted`,
+/// a reached epoch cap or an empty vault skips the reward instead of failing.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_pit_burn_reward<'info>(
+    sacred_waste_pit: &AccountInfo<'info>,
+    pit_bump: u8,
+    rewards_enabled: bool,
+    rewards: &mut Option<Box<Account<'info, PitRewards>>>,
+    vault: &Option<Box<Account<'info, TokenAccount>>>,
+    destination: &Option<Box<Account<'info, TokenAccount>>>,
+    stats: &mut BurnerStats,
+    token_program: &Program<'info, Token>,
+    now: i64,
+    skip_when_exhausted: bool,
+) -> Result<u64> {
+    match (rewards_enabled, rewards, vault, destination) {
+        (true, Some(rewards), Some(vault), Some(destination)) => pay_burn_reward(
+            sacred_waste_pit,
+            pit_bump,
+            rewards,
+            vault,
+            destination,
+            stats,
+            token_program,
+            now,
+            skip_when_exhausted,
+        ),
+        (false, None, None, None) => Ok(0),
+        _ => err!(RewardError::WrongRewardAccounts),
+    }
+}
+
+/// Pay the burn reward for `stats.burn_count` (already incremented for this
+/// burn) from the vault to the burner's token account, signed by the pit
+#[allow(clippy::too_many_arguments)]
+fn pay_burn_reward<'info>(
+    sacred_waste_pit: &AccountInfo<'info>,
+    pit_bump: u8,
+    rewards: &mut PitRewards,
+    vault: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    stats: &mut BurnerStats,
+    token_program: &Program<'info, Token>,
+    now: i64,
+    skip_when_exhausted: bool,
+) -> Result<u64> {
+    require_keys_eq!(vault.key(), rewards.vault, RewardError::InvalidRewardVault);
+    require_keys_eq!(
+        destination.mint,
+        rewards.reward_mint,
+        RewardError::InvalidRewardAccount
+    );
+    require_keys_eq!(
+        destination.owner,
+        stats.burner,
+        RewardError::InvalidRewardAccount
+    );
+
+    let multiplier_bps = rewards
+        .tiers
+        .iter()
+        .rev()
+        .find(|tier| stats.burn_count >= tier.min_burn_count)
+        .map_or(10_000, |tier| tier.multiplier_bps);
+    let amount = (rewards.reward_per_burn as u128)
+        .checked_mul(multiplier_bps as u128)
+        .map(|scaled| scaled / 10_000)
+        .and_then(|amount| u64::try_from(amount).ok())
+        .ok_or(RewardError::MathOverflow)?;
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    // Roll over to a new epoch once the current one has elapsed
+    if now >= rewards.epoch_start.saturating_add(rewards.epoch_duration) {
+        let elapsed_epochs = (now - rewards.epoch_start) / rewards.epoch_duration;
+        rewards.epoch_start += elapsed_epochs * rewards.epoch_duration;
+        rewards.epoch_emitted = 0;
+    }
+
+    let epoch_emitted = rewards
+        .epoch_emitted
+        .checked_add(amount)
+        .ok_or(RewardError::MathOverflow)?;
+    if skip_when_exhausted && (epoch_emitted > rewards.epoch_cap || vault.amount < amount) {
+        msg!("Burn reward skipped: epoch cap reached or vault empty");
+        emit!(BurnRewardSkipped {
+            burner: stats.burner,
+            amount,
+        });
+        return Ok(0);
+    }
+    require!(epoch_emitted <= rewards.epoch_cap, RewardError::EpochCapReached);
+    require!(vault.amount >= amount, RewardError::RewardVaultEmpty);
+
+    let seeds = &[b"sacred_waste_pit".as_ref(), &[pit_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: sacred_waste_pit.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    rewards.epoch_emitted = epoch_emitted;
+    rewards.total_emitted = rewards
+        .total_emitted
+        .checked_add(amount)
+        .ok_or(RewardError::MathOverflow)?;
+    stats.total_rewards = stats
+        .total_rewards
+        .checked_add(amount)
+        .ok_or(RewardError::MathOverflow)?;
+
+    emit!(BurnRewardPaid {
+        burner: stats.burner,
+        amount,
+        multiplier_bps,
+        burn_count: stats.burn_count,
+    });
+
+    Ok(amount)
+}
+
+#[event]
+pub struct RewardConfigUpdated {
+    pub reward_per_burn: u64,
+    pub epoch_duration: i64,
+    pub epoch_cap: u64,
+}
+
+#[event]
+pub struct BurnRewardPaid {
+    pub burner: Pubkey,
+    pub amount: u64,
+    pub multiplier_bps: u16,
+    pub burn_count: u64,
+}
+
+#[event]
+pub struct BurnRewardSkipped {
+    pub burner: Pubkey,
+    pub amount: u64,
+}
+
+#[error_code]
+pub enum RewardError {
+    #[msg("Caller is not the pit authority")]
+    Unauthorized,
+    #[msg("Epoch duration must be positive")]
+    InvalidEpochDuration,
+    #[msg("Too many reward tiers")]
+    TooManyTiers,
+    #[msg("Reward tiers must be strictly ascending by burn count")]
+    TiersNotAscending,
+    #[msg("Reward vault does not match the pit rewards config")]
+    InvalidRewardVault,
+    #[msg("Reward account must be the burner's reward token account")]
+    InvalidRewardAccount,
+    #[msg("Reward accounts must be supplied exactly while pit rewards are initialized")]
+    WrongRewardAccounts,
+    #[msg("Reward emission cap reached for this epoch")]
+    EpochCapReached,
+    #[msg("Reward vault has run dry")]
+    RewardVaultEmpty,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
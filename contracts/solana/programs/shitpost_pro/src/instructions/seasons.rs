@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::leaderboard::rank_burner;
+use crate::state::{SacredWastePit, Season, SeasonBurnerStats, LeaderboardEntry};
+
+#[derive(Accounts)]
+pub struct OpenSeason<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == sacred_waste_pit.authority @ SeasonError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump,
+        constraint = !sacred_waste_pit.season_active @ SeasonError::SeasonAlreadyActive
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Season::LEN,
+        seeds = [b"season", (sacred_waste_pit.current_season + 1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    #[account(
+        constraint = authority.key() == sacred_waste_pit.authority @ SeasonError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sacred_waste_pit"],
+        bump = sacred_waste_pit.bump,
+        constraint = sacred_waste_pit.season_active @ SeasonError::NoActiveSeason
+    )]
+    pub sacred_waste_pit: Account<'info, SacredWastePit>,
+
+    #[account(
+        mut,
+        seeds = [b"season", sacred_waste_pit.current_season.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+}
+
+pub fn handler_open_season(ctx: Context<OpenSeason>, start_ts: i64, end_ts: i64) -> Result<()> {
+    require!(end_ts > start_ts, SeasonError::InvalidSeasonWindow);
+    require!(end_ts > Clock::get()?.unix_timestamp, SeasonError::InvalidSeasonWindow);
+
+    let pit = &mut ctx.accounts.sacred_waste_pit;
+    let season_id = pit.current_season.checked_add(1).ok_or(SeasonError::MathOverflow)?;
+    pit.current_season = season_id;
+    pit.season_active = true;
+
+    let season = &mut ctx.accounts.season;
+    season.season_id = season_id;
+    season.start_ts = start_ts;
+    season.end_ts = end_ts;
+    season.total_burns = 0;
+    season.standings = Vec::new();
+    season.closed_at = None;
+    season.bump = ctx.bumps.season;
+
+    msg!("Season {} opened", season_id);
+    emit!(SeasonOpened {
+        season_id,
+        start_ts,
+        end_ts,
+    });
+
+    Ok(())
+}
+
+/// Close the active season. Its standings stop changing from here on and can
+/// be used to distribute season rewards.
+pub fn handler_close_season(ctx: Context<CloseSeason>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.sacred_waste_pit.season_active = false;
+
+    let season = &mut ctx.accounts.season;
+    season.closed_at = Some(now);
+
+    msg!("Season {} closed", season.season_id);
+    emit!(SeasonClosed {
+        season_id: season.season_id,
+        total_burns: season.total_burns,
+        standings: season.standings.clone(),
+        closed_at: now,
+    });
+
+    Ok(())
+}
+
+/// Count a burn toward the active season. The season accounts are required
+/// while a season is open and must be omitted otherwise; burns outside the
+/// season window are not counted.
+pub(crate) fn record_season_burn(
+    pit: &SacredWastePit,
+    season: &mut Option<Box<Account<Season>>>,
+    stats: &mut Option<Box<Account<SeasonBurnerStats>>>,
+    stats_bump: u8,
+    burner: Pubkey,
+    now: i64,
+) -> Result<()> {
+    match (pit.season_active, season, stats) {
+        (true, Some(season), Some(stats)) => {
+            // Initialize before the window check so a stats account created by
+            // an out-of-window burn is never left zeroed
+            if stats.burner == Pubkey::default() {
+                stats.season_id = season.season_id;
+                stats.burner = burner;
+                stats.bump = stats_bump;
+            }
+            if now < season.start_ts || now >= season.end_ts {
+                return Ok(());
+            }
+
+            stats.burn_count = stats.burn_count.checked_add(1).ok_or(SeasonError::MathOverflow)?;
+            season.total_burns = season
+                .total_burns
+                .checked_add(1)
+                .ok_or(SeasonError::MathOverflow)?;
+            rank_burner(&mut season.standings, burner, stats.burn_count);
+
+            Ok(())
+        }
+        (false, None, None) => Ok(()),
+        _ => err!(SeasonError::WrongSeasonAccounts),
+    }
+}
+
+#[event]
+pub struct SeasonOpened {
+    pub season_id: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct SeasonClosed {
+    pub season_id: u64,
+    pub total_burns: u64,
+    pub standings: Vec<LeaderboardEntry>,
+    pub closed_at: i64,
+}
+
+#[error_code]
+pub enum SeasonError {
+    #[msg("Caller is not the pit authority")]
+    Unauthorized,
+    #[msg("A season is already active")]
+    SeasonAlreadyActive,
+    #[msg("No season is active")]
+    NoActiveSeason,
+    #[msg("Season must end after it starts and in the future")]
+    InvalidSeasonWindow,
+    #[msg("Season accounts must be supplied exactly while a season is active")]
+    WrongSeasonAccounts,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
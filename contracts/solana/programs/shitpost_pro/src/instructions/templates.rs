@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::state::{Template, CreatorEarnings};
+
+#[derive(Accounts)]
+#[instruction(template_hash: [u8; 32])]
+pub struct RegisterTemplate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Template::LEN,
+        seeds = [b"template", template_hash.as_ref()],
+        bump
+    )]
+    pub template: Account<'info, Template>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_register_template(
+    ctx: Context<RegisterTemplate>,
+    template_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    require!(!uri.is_empty(), TemplateError::EmptyUri);
+    require!(uri.len() <= Template::MAX_URI_LEN, TemplateError::UriTooLong);
+
+    let template = &mut ctx.accounts.template;
+    template.template_hash = template_hash;
+    template.creator = ctx.accounts.creator.key();
+    template.uri = uri;
+    template.usage_count = 0;
+    template.total_earned = 0;
+    template.created_at = Clock::get()?.unix_timestamp;
+    template.bump = ctx.bumps.template;
+
+    msg!("Template registered by {}", template.creator);
+    emit!(TemplateRegistered {
+        template: template.key(),
+        template_hash,
+        creator: template.creator,
+        uri: template.uri.clone(),
+    });
+
+    Ok(())
+}
+
+/// Credit a mint to its template
+pub(crate) fn record_template_use(template: &mut Template) -> Result<()> {
+    template.usage_count = template
+        .usage_count
+        .checked_add(1)
+        .ok_or(TemplateError::MathOverflow)?;
+    Ok(())
+}
+
+/// Pay the template creator `share_bps` of `premium_fee` from the minter and
+/// accumulate it on the template and the creator's earnings. Returns the share,
+/// or zero when it would leave the creator's wallet below the rent minimum.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_template_creator<'info>(
+    template: &mut Account<'info, Template>,
+    creator: &AccountInfo<'info>,
+    earnings: &mut Account<'info, CreatorEarnings>,
+    earnings_bump: u8,
+    minter: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    premium_fee: u64,
+    share_bps: u16,
+) -> Result<u64> {
+    require_keys_eq!(creator.key(), template.creator, TemplateError::WrongCreator);
+
+    if earnings.creator == Pubkey::default() {
+        earnings.creator = creator.key();
+        earnings.bump = earnings_bump;
+    }
+
+    let share = (premium_fee as u128 * share_bps as u128 / 10_000) as u64;
+    if share == 0 || !clears_rent(creator, share)? {
+        return Ok(0);
+    }
+
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: minter.clone(),
+                to: creator.clone(),
+            },
+        ),
+        share,
+    )?;
+
+    template.total_earned = template
+        .total_earned
+        .checked_add(share)
+        .ok_or(TemplateError::MathOverflow)?;
+    earnings.total_earned = earnings
+        .total_earned
+        .checked_add(share)
+        .ok_or(TemplateError::MathOverflow)?;
+
+    emit!(TemplateRevenuePaid {
+        template: template.key(),
+        creator: creator.key(),
+        amount: share,
+        template_total_earned: template.total_earned,
+        creator_total_earned: earnings.total_earned,
+    });
+
+    Ok(share)
+}
+
+/// Whether `recipient` is rent exempt once it receives `amount`. A share too
+/// small to fund an empty wallet would fail the whole mint, so it is skipped
+/// and goes to the treasury instead.
+pub(crate) fn clears_rent(recipient: &AccountInfo, amount: u64) -> Result<bool> {
+    let balance = recipient.lamports().saturating_add(amount);
+    Ok(Rent::get()?.is_exempt(balance, recipient.data_len()))
+}
+
+#[event]
+pub struct TemplateRevenuePaid {
+    pub template: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub template_total_earned: u64,
+    pub creator_total_earned: u64,
+}
+
+#[event]
+pub struct TemplateRegistered {
+    pub template: Pubkey,
+    pub template_hash: [u8; 32],
+    pub creator: Pubkey,
+    pub uri: String,
+}
+
+#[error_code]
+pub enum TemplateError {
+    #[msg("Template URI is empty")]
+    EmptyUri,
+    #[msg("Template URI is too long")]
+    UriTooLong,
+    #[msg("Creator account does not match the template creator")]
+    WrongCreator,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
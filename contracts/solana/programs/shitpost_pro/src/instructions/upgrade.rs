@@ -0,0 +1,461 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Burn, CloseAccount};
+use anchor_spl::associated_token::AssociatedToken;
+
+use crate::instructions::burn::read_collection_uri;
+use crate::instructions::mint::{create_metadata_account, create_master_edition};
+use crate::state::{CollectionConfig, BurnedArt, CardTier, TokenTier, UpgradeRecipe};
+
+/// Accounts per input card in `remaining_accounts`:
+/// mint, token account, metadata, token tier PDA, burned art PDA
+const ACCOUNTS_PER_INPUT: usize = 5;
+
+#[derive(Accounts)]
+#[instruction(input_tier: CardTier)]
+pub struct SetUpgradeRecipe<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == collection_config.authority @ UpgradeError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = UpgradeRecipe::LEN,
+        seeds = [b"upgrade_recipe", collection_config.key().as_ref(), &[input_tier as u8]],
+        bump
+    )]
+    pub upgrade_recipe: Account<'info, UpgradeRecipe>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnAndUpgrade<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_config.slug.as_bytes()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        seeds = [
+            b"upgrade_recipe",
+            collection_config.key().as_ref(),
+            &[upgrade_recipe.input_tier as u8]
+        ],
+        bump = upgrade_recipe.bump,
+        constraint = upgrade_recipe.input_count > 0 @ UpgradeError::RecipeDisabled
+    )]
+    pub upgrade_recipe: Box<Account<'info, UpgradeRecipe>>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = collection_config,
+        mint::freeze_authority = collection_config,
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = TokenTier::LEN,
+        seeds = [b"token_tier", mint.key().as_ref()],
+        bump
+    )]
+    pub token_tier: Box<Account<'info, TokenTier>>,
+
+    /// CHECK: Metadata account created via CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account created via CPI
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn handler_set_recipe(
+    ctx: Context<SetUpgradeRecipe>,
+    input_tier: CardTier,
+    input_count: u8,
+    output_tier: CardTier,
+) -> Result<()> {
+    require!(
+        input_count <= UpgradeRecipe::MAX_INPUTS,
+        UpgradeError::TooManyInputs
+    );
+    require!(
+        (output_tier as u8) > (input_tier as u8),
+        UpgradeError::OutputTierNotHigher
+    );
+
+    let recipe = &mut ctx.accounts.upgrade_recipe;
+    recipe.collection = ctx.accounts.collection_config.key();
+    recipe.input_tier = input_tier;
+    recipe.input_count = input_count;
+    recipe.output_tier = output_tier;
+    recipe.bump = ctx.bumps.upgrade_recipe;
+
+    msg!(
+        "Upgrade recipe set: {} x {} -> {}",
+        input_count,
+        input_tier.label(),
+        output_tier.label()
+    );
+    emit!(UpgradeRecipeSet {
+        collection: recipe.collection,
+        input_tier,
+        input_count,
+        output_tier,
+    });
+
+    Ok(())
+}
+
+pub fn handler_burn_and_upgrade<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BurnAndUpgrade<'info>>,
+) -> Result<()> {
+    let recipe = &ctx.accounts.upgrade_recipe;
+    let input_count = recipe.input_count as usize;
+    require!(
+        ctx.remaining_accounts.len() == input_count * ACCOUNTS_PER_INPUT,
+        UpgradeError::WrongInputCount
+    );
+
+    let clock = Clock::get()?;
+    let config_key = ctx.accounts.collection_config.key();
+    let mut burned_mints = Vec::with_capacity(input_count);
+
+    for input in ctx.remaining_accounts.chunks(ACCOUNTS_PER_INPUT) {
+        let burned_mint = burn_input(
+            input,
+            recipe.input_tier,
+            &config_key,
+            &ctx.accounts.owner,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            clock.unix_timestamp,
+        )?;
+        burned_mints.push(burned_mint);
+    }
+
+    let output_tier = recipe.output_tier;
+    let config = &mut ctx.accounts.collection_config;
+    config.total_burned = config
+        .total_burned
+        .checked_add(input_count as u64)
+        .ok_or(UpgradeError::MathOverflow)?;
+
+    let token_id = config.total_minted;
+    config.total_minted = config.total_minted.checked_add(1).ok_or(UpgradeError::MathOverflow)?;
+
+    let name = format!("{} #{} ({})", config.name, token_id, output_tier.label());
+    require!(
+        name.len() <= mpl_token_metadata::MAX_NAME_LENGTH,
+        UpgradeError::NameTooLong
+    );
+    let uri = format!("{}{}.json", config.uri, token_id);
+    require!(
+        uri.len() <= CollectionConfig::MAX_URI_LEN,
+        UpgradeError::UriTooLong
+    );
+
+    // Mint the upgraded card
+    let seeds = &[
+        b"collection_config".as_ref(),
+        config.slug.as_bytes(),
+        &[config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    create_metadata_account(
+        &ctx.accounts.metadata,
+        &ctx.accounts.mint.to_account_info(),
+        &config.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        &ctx.accounts.token_metadata_program,
+        name,
+        config.symbol.clone(),
+        uri.clone(),
+        signer_seeds,
+    )?;
+
+    create_master_edition(
+        &ctx.accounts.master_edition,
+        &ctx.accounts.mint.to_account_info(),
+        &config.to_account_info(),
+        &ctx.accounts.metadata,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        &ctx.accounts.token_metadata_program,
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    let token_tier = &mut ctx.accounts.token_tier;
+    token_tier.mint = ctx.accounts.mint.key();
+    token_tier.tier = output_tier;
+    token_tier.bump = ctx.bumps.token_tier;
+
+    msg!(
+        "Upgraded {} cards into {} (token #{})",
+        input_count,
+        ctx.accounts.mint.key(),
+        token_id
+    );
+    emit!(CardUpgraded {
+        owner: ctx.accounts.owner.key(),
+        burned_mints,
+        mint: ctx.accounts.mint.key(),
+        token_id,
+        tier: output_tier,
+        token_uri: uri,
+    });
+
+    Ok(())
+}
+
+/// Validate one input card, record it as `BurnedArt`, burn it and close its
+/// token account and tier record. Returns the burned mint.
+fn burn_input<'info>(
+    input: &'info [AccountInfo<'info>],
+    input_tier: CardTier,
+    collection_config: &Pubkey,
+    owner: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    now: i64,
+) -> Result<Pubkey> {
+    let [mint_info, token_account_info, metadata_info, token_tier_info, burned_art_info] = input
+    else {
+        return err!(UpgradeError::WrongInputCount);
+    };
+
+    let mint = Account::<Mint>::try_from(mint_info)?;
+    let token_account = Account::<TokenAccount>::try_from(token_account_info)?;
+    require_keys_eq!(token_account.mint, mint.key(), UpgradeError::NotOwner);
+    require_keys_eq!(token_account.owner, owner.key(), UpgradeError::NotOwner);
+    require!(token_account.amount == 1, UpgradeError::NotOwner);
+
+    // Must be a card from this collection
+    let (metadata_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint.key());
+    require_keys_eq!(metadata_info.key(), metadata_key, UpgradeError::InvalidInputAccount);
+    let token_uri = read_collection_uri(metadata_info, collection_config)?;
+
+    // Cards without a tier record are base tier; tiered cards give up their record
+    let (token_tier_key, _) =
+        Pubkey::find_program_address(&[b"token_tier", mint.key().as_ref()], &crate::ID);
+    require_keys_eq!(token_tier_info.key(), token_tier_key, UpgradeError::InvalidInputAccount);
+    let tier = if token_tier_info.data_is_empty() {
+        CardTier::default()
+    } else {
+        let token_tier = Account::<TokenTier>::try_from(token_tier_info)?;
+        let tier = token_tier.tier;
+        token_tier.close(owner.to_account_info())?;
+        tier
+    };
+    require!(tier == input_tier, UpgradeError::WrongInputTier);
+
+    // Record the burn for the gallery, as `burn` does
+    let (burned_art_key, burned_art_bump) =
+        Pubkey::find_program_address(&[b"burned_art", mint.key().as_ref()], &crate::ID);
+    require_keys_eq!(burned_art_info.key(), burned_art_key, UpgradeError::InvalidInputAccount);
+    create_pda_account(
+        owner,
+        burned_art_info,
+        BurnedArt::LEN,
+        &[b"burned_art", mint.key().as_ref(), &[burned_art_bump]],
+        system_program,
+    )?;
+    let burned_art = BurnedArt {
+        artist: owner.key(),
+        token_uri,
+        burned_at: now,
+        original_mint: mint.key(),
+        bump: burned_art_bump,
+    };
+    burned_art.try_serialize(&mut &mut burned_art_info.try_borrow_mut_data()?[..])?;
+
+    token::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint_info.clone(),
+                from: token_account_info.clone(),
+                authority: owner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    token::close_account(
+        CpiContext::new(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: token_account_info.clone(),
+                destination: owner.to_account_info(),
+                authority: owner.to_account_info(),
+            },
+        ),
+    )?;
+
+    Ok(mint.key())
+}
+
+/// Create a program-owned PDA the way Anchor's `init` does, so lamports sent to
+/// the address beforehand cannot block it: an unfunded address is created
+/// outright, a pre-funded one is topped up to rent exemption, allocated and
+/// assigned
+fn create_pda_account<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+
+    if current == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent.saturating_sub(current);
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+#[event]
+pub struct UpgradeRecipeSet {
+    pub collection: Pubkey,
+    pub input_tier: CardTier,
+    pub input_count: u8,
+    pub output_tier: CardTier,
+}
+
+#[event]
+pub struct CardUpgraded {
+    pub owner: Pubkey,
+    pub burned_mints: Vec<Pubkey>,
+    pub mint: Pubkey,
+    pub token_id: u64,
+    pub tier: CardTier,
+    pub token_uri: String,
+}
+
+#[error_code]
+pub enum UpgradeError {
+    #[msg("Caller is not the authority")]
+    Unauthorized,
+    #[msg("Upgrade recipe is disabled")]
+    RecipeDisabled,
+    #[msg("Too many input cards for a recipe")]
+    TooManyInputs,
+    #[msg("Output tier must be higher than the input tier")]
+    OutputTierNotHigher,
+    #[msg("Remaining accounts do not match the recipe's input count")]
+    WrongInputCount,
+    #[msg("Input account does not match its expected address")]
+    InvalidInputAccount,
+    #[msg("You do not own this NFT")]
+    NotOwner,
+    #[msg("Input card is not of the recipe's tier")]
+    WrongInputTier,
+    #[msg("Upgraded card name exceeds the Metaplex limit")]
+    NameTooLong,
+    #[msg("Upgraded card URI exceeds the Metaplex limit")]
+    UriTooLong,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
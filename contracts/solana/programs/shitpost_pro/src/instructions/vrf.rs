@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::instructions::reveal::write_traits;
+use crate::state::{TokenState, TokenTier, TokenTraits, TraitTable, VrfRequest};
+
+/// Instruction asking `oracle_program` to fulfil `request` with randomness.
+/// The oracle exposes an Anchor `request_randomness(seed)` instruction taking
+/// `(payer, request)` and calls back into `fulfill_randomness` signed by its
+/// `[b"oracle_authority"]` PDA, as the local `mock_vrf` program does.
+fn request_instruction(
+    oracle_program: &Pubkey,
+    payer: &Pubkey,
+    request: &Pubkey,
+    seed: [u8; 32],
+) -> Instruction {
+    let mut data = hash(b"global:request_randomness").to_bytes()[..8].to_vec();
+    data.extend_from_slice(&seed);
+
+    Instruction {
+        program_id: *oracle_program,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*request, false),
+        ],
+        data,
+    }
+}
+
+/// Key that must sign `oracle_program`'s `fulfill_randomness` callback
+fn callback_authority(oracle_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"oracle_authority"], oracle_program).0
+}
+
+#[derive(Accounts)]
+pub struct FulfillRandomness<'info> {
+    #[account(
+        constraint = oracle_authority.key() == callback_authority(&vrf_request.oracle)
+            @ VrfError::InvalidOracleAuthority
+    )]
+    pub oracle_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Only used to derive the token PDAs
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vrf_request", mint.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = !vrf_request.fulfilled @ VrfError::AlreadyFulfilled
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+
+    #[account(
+        seeds = [b"token_state", mint.key().as_ref()],
+        bump = token_state.bump
+    )]
+    pub token_state: Account<'info, TokenState>,
+
+    #[account(
+        seeds = [b"trait_table", token_state.collection_config.as_ref()],
+        bump = trait_table.bump
+    )]
+    pub trait_table: Account<'info, TraitTable>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = TokenTraits::LEN,
+        seeds = [b"token_traits", mint.key().as_ref()],
+        bump
+    )]
+    pub token_traits: Account<'info, TokenTraits>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = TokenTier::LEN,
+        seeds = [b"token_tier", mint.key().as_ref()],
+        bump
+    )]
+    pub token_tier: Account<'info, TokenTier>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the token's `VrfRequest` and ask the oracle for randomness
+pub(crate) fn request_randomness<'info>(
+    request: &mut Account<'info, VrfRequest>,
+    request_bump: u8,
+    oracle_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    mint: Pubkey,
+    token_id: u64,
+) -> Result<()> {
+    let seed = hashv(&[mint.as_ref(), &token_id.to_le_bytes()]).to_bytes();
+
+    request.mint = mint;
+    request.oracle = oracle_program.key();
+    request.seed = seed;
+    request.requested_at = Clock::get()?.unix_timestamp;
+    request.fulfilled = false;
+    request.bump = request_bump;
+
+    let ix = request_instruction(
+        &oracle_program.key(),
+        &payer.key(),
+        &request.key(),
+        seed,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[payer.clone(), request.to_account_info(), oracle_program.clone()],
+    )?;
+
+    emit!(RandomnessRequested {
+        mint,
+        oracle: oracle_program.key(),
+        seed,
+    });
+
+    Ok(())
+}
+
+/// Oracle callback: roll the token's traits from the delivered randomness
+pub fn handler_fulfill_randomness(
+    ctx: Context<FulfillRandomness>,
+    randomness: [u8; 32],
+) -> Result<()> {
+    let request = &mut ctx.accounts.vrf_request;
+    request.fulfilled = true;
+
+    let seed = hashv(&[&randomness, &request.seed]).to_bytes();
+    write_traits(
+        &mut ctx.accounts.token_traits,
+        ctx.bumps.token_traits,
+        &mut ctx.accounts.token_tier,
+        ctx.bumps.token_tier,
+        &ctx.accounts.token_state,
+        &ctx.accounts.trait_table,
+        seed,
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct RandomnessRequested {
+    pub mint: Pubkey,
+    pub oracle: Pubkey,
+    pub seed: [u8; 32],
+}
+
+#[error_code]
+pub enum VrfError {
+    #[msg("Callback is not signed by the request's oracle")]
+    InvalidOracleAuthority,
+    #[msg("Randomness request has already been fulfilled")]
+    AlreadyFulfilled,
+}
@@ -4,6 +4,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::{BurnReason, CardTier, CardTraits, PitStorageMode, RewardTier};
 
 declare_id!("7F6SJmYgF8iEF9DQmpDUuboTRs4qYt5hr27TcXCuykDo");
 
@@ -11,21 +12,81 @@ declare_id!("7F6SJmYgF8iEF9DQmpDUuboTRs4qYt5hr27TcXCuykDo");
 pub mod shitpost_pro {
     use super::*;
 
-    /// Initialize the collection configuration
+    /// Initialize a collection configuration, keyed by its slug
     pub fn initialize(
         ctx: Context<Initialize>,
+        slug: String,
         name: String,
         symbol: String,
         uri: String,
         treasury: Pubkey,
         premium_fee: u64,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, name, symbol, uri, treasury, premium_fee)
+        instructions::initialize::handler(ctx, slug, name, symbol, uri, treasury, premium_fee)
     }
 
-    /// Mint a new NFT with premium fee
-    pub fn mint_with_premium(ctx: Context<MintNftWithPremium>, uri: String) -> Result<()> {
-        instructions::mint::handler_with_premium(ctx, uri)
+    /// Mint a new NFT with premium fee. Without a `uri` the metadata URI is
+    /// derived from the collection base URI; a free-form `uri` requires the
+    /// backend signer's approval, as does a trait `commitment`. Supplying the
+    /// VRF accounts instead requests randomness from the configured oracle.
+    /// `content_hash` identifies the art; each hash can be minted only once.
+    pub fn mint_with_premium(
+        ctx: Context<MintNftWithPremium>,
+        uri: Option<String>,
+        commitment: Option<[u8; 32]>,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::mint::handler_with_premium(ctx, uri, commitment, content_hash)
+    }
+
+    /// Check the committed nonce and roll the token's traits on-chain
+    pub fn reveal_traits(ctx: Context<RevealTraits>, nonce: [u8; 32]) -> Result<()> {
+        instructions::reveal::handler_reveal_traits(ctx, nonce)
+    }
+
+    /// Set the collection's trait roll weights, once at any time and then
+    /// until the drop starts
+    pub fn set_trait_table(
+        ctx: Context<SetTraitTable>,
+        aura_weights: [u16; 5],
+        tier_weights: [u16; 5],
+        class_weights: [u16; 5],
+        drop_starts_at: i64,
+    ) -> Result<()> {
+        instructions::reveal::handler_set_trait_table(
+            ctx,
+            aura_weights,
+            tier_weights,
+            class_weights,
+            drop_starts_at,
+        )
+    }
+
+    /// VRF oracle callback that rolls a token's traits from its randomness
+    pub fn fulfill_randomness(
+        ctx: Context<FulfillRandomness>,
+        randomness: [u8; 32],
+    ) -> Result<()> {
+        instructions::vrf::handler_fulfill_randomness(ctx, randomness)
+    }
+
+    /// Read a revealed token's traits (view / CPI getter)
+    pub fn get_token_traits(ctx: Context<GetTokenTraits>) -> Result<CardTraits> {
+        instructions::reveal::handler_get_token_traits(ctx)
+    }
+
+    /// Replace a minted token's placeholder metadata URI (backend-signed, one time)
+    pub fn reveal(ctx: Context<Reveal>, uri: String) -> Result<()> {
+        instructions::reveal::handler_reveal(ctx, uri)
+    }
+
+    /// Register a meme template under its hash, credited to the caller
+    pub fn register_template(
+        ctx: Context<RegisterTemplate>,
+        template_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::templates::handler_register_template(ctx, template_hash, uri)
     }
 
     /// Update treasury address
@@ -33,6 +94,250 @@ pub mod shitpost_pro {
         instructions::admin::handler_set_treasury(ctx, treasury)
     }
 
+    /// Burn an NFT and record in gallery
+    pub fn burn(ctx: Context<BurnNft>) -> Result<()> {
+        instructions::burn::handler_burn(ctx)
+    }
+
+    /// Burn an NFT to Sacred Waste Pit with a reason and optional epitaph
+    pub fn burn_to_waste(
+        ctx: Context<BurnToWaste>,
+        reason: BurnReason,
+        message: Option<String>,
+    ) -> Result<()> {
+        instructions::burn::handler_to_waste(ctx, reason, message)
+    }
+
+    /// Move an NFT into escrow to be burned once its cancel window closes
+    pub fn schedule_burn(
+        ctx: Context<ScheduleBurn>,
+        reason: BurnReason,
+        message: Option<String>,
+    ) -> Result<()> {
+        instructions::delayed_burn::handler_schedule_burn(ctx, reason, message)
+    }
+
+    /// Return a scheduled NFT to its owner during the cancel window
+    pub fn cancel_burn(ctx: Context<CancelBurn>) -> Result<()> {
+        instructions::delayed_burn::handler_cancel_burn(ctx)
+    }
+
+    /// Burn an escrowed NFT to the pit after its cancel window (callable by anyone)
+    pub fn execute_burn(ctx: Context<ExecuteBurn>) -> Result<()> {
+        instructions::delayed_burn::handler_execute_burn(ctx)
+    }
+
+    /// Set how long newly scheduled burns can be cancelled
+    pub fn set_cancel_window(ctx: Context<SetCancelWindow>, cancel_window: i64) -> Result<()> {
+        instructions::delayed_burn::handler_set_cancel_window(ctx, cancel_window)
+    }
+
+    /// Burn a verified NFT from an allowlisted foreign collection to the pit
+    pub fn burn_foreign_to_pit(ctx: Context<BurnForeignToPit>) -> Result<()> {
+        instructions::burn::handler_foreign_to_pit(ctx)
+    }
+
+    /// Burn cards listed in `remaining_accounts` per the recipe and mint one
+    /// higher-tier card
+    pub fn burn_and_upgrade<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BurnAndUpgrade<'info>>,
+    ) -> Result<()> {
+        instructions::upgrade::handler_burn_and_upgrade(ctx)
+    }
+
+    /// Create or update the upgrade recipe for an input tier
+    pub fn set_upgrade_recipe(
+        ctx: Context<SetUpgradeRecipe>,
+        input_tier: CardTier,
+        input_count: u8,
+        output_tier: CardTier,
+    ) -> Result<()> {
+        instructions::upgrade::handler_set_recipe(ctx, input_tier, input_count, output_tier)
+    }
+
+    /// Initialize the Sacred Waste Pit
+    pub fn initialize_pit(ctx: Context<InitializePit>) -> Result<()> {
+        instructions::pit::handler_init(ctx)
+    }
+
+    /// Deposit a burn record to the pit, with an optional reason and epitaph.
+    /// Authorized programs call this via CPI, signing with their
+    /// `[b"pit_caller"]` PDA.
+    pub fn deposit_burn(
+        ctx: Context<DepositBurn>,
+        burner: Pubkey,
+        metadata: String,
+        reason: Option<BurnReason>,
+        message: Option<String>,
+    ) -> Result<()> {
+        instructions::pit::handler_deposit(ctx, burner, metadata, reason, message)
+    }
+
+    /// Switch new pit burns between per-burn records and bucket pages
+    pub fn set_pit_storage_mode(
+        ctx: Context<SetPitStorageMode>,
+        storage_mode: PitStorageMode,
+    ) -> Result<()> {
+        instructions::pit::handler_set_storage_mode(ctx, storage_mode)
+    }
+
+    /// Open the pit's current burn bucket
+    pub fn open_pit_bucket(ctx: Context<OpenPitBucket>, bucket_index: u64) -> Result<()> {
+        instructions::pit::handler_open_bucket(ctx, bucket_index)
+    }
+
+    /// Close a past burn bucket and refund its rent payer
+    pub fn close_pit_bucket(ctx: Context<ClosePitBucket>) -> Result<()> {
+        instructions::pit::handler_close_bucket(ctx)
+    }
+
+    /// Enable or disable burn record closing and set the archival period
+    pub fn set_record_archival(
+        ctx: Context<SetRecordArchival>,
+        enabled: bool,
+        archival_period: i64,
+    ) -> Result<()> {
+        instructions::pit::handler_set_record_archival(ctx, enabled, archival_period)
+    }
+
+    /// Close the caller's archived burn records and reclaim their rent
+    pub fn close_burn_record(ctx: Context<CloseBurnRecord>) -> Result<()> {
+        instructions::pit::handler_close_burn_record(ctx)
+    }
+
+    /// Allow a program to deposit burns into the pit
+    pub fn authorize_program(ctx: Context<AuthorizeProgram>) -> Result<()> {
+        instructions::pit::handler_authorize(ctx)
+    }
+
+    /// Revoke a program's pit deposit authorization
+    pub fn revoke_program(ctx: Context<RevokeProgram>) -> Result<()> {
+        instructions::pit::handler_revoke(ctx)
+    }
+
+    /// Create the pit's top-burner leaderboard
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+        instructions::leaderboard::handler_init_leaderboard(ctx)
+    }
+
+    /// Open the next burn season
+    pub fn open_season(ctx: Context<OpenSeason>, start_ts: i64, end_ts: i64) -> Result<()> {
+        instructions::seasons::handler_open_season(ctx, start_ts, end_ts)
+    }
+
+    /// Close the active season and freeze its standings
+    pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+        instructions::seasons::handler_close_season(ctx)
+    }
+
+    /// Create the pit's burn-to-earn config and reward vault
+    pub fn initialize_rewards(
+        ctx: Context<InitializeRewards>,
+        reward_per_burn: u64,
+        epoch_duration: i64,
+        epoch_cap: u64,
+    ) -> Result<()> {
+        instructions::rewards::handler_init_rewards(ctx, reward_per_burn, epoch_duration, epoch_cap)
+    }
+
+    /// Update the per-burn reward and epoch emission cap
+    pub fn set_reward_config(
+        ctx: Context<SetRewardConfig>,
+        reward_per_burn: u64,
+        epoch_duration: i64,
+        epoch_cap: u64,
+    ) -> Result<()> {
+        instructions::rewards::handler_set_reward_config(
+            ctx,
+            reward_per_burn,
+            epoch_duration,
+            epoch_cap,
+        )
+    }
+
+    /// Replace the burn-count reward multiplier tiers
+    pub fn set_reward_tiers(ctx: Context<SetRewardConfig>, tiers: Vec<RewardTier>) -> Result<()> {
+        instructions::rewards::handler_set_reward_tiers(ctx, tiers)
+    }
+
+    /// Allow verified NFTs of a foreign collection into the pit
+    pub fn allow_collection(ctx: Context<AllowCollection>) -> Result<()> {
+        instructions::pit::handler_allow_collection(ctx)
+    }
+
+    /// Remove a foreign collection from the pit allowlist
+    pub fn disallow_collection(ctx: Context<DisallowCollection>) -> Result<()> {
+        instructions::pit::handler_disallow_collection(ctx)
+    }
+
+    /// Let a wallet freeze and thaw the collection's NFTs
+    pub fn add_moderator(ctx: Context<AddModerator>, moderator: Pubkey) -> Result<()> {
+        instructions::moderation::handler_add_moderator(ctx, moderator)
+    }
+
+    /// Revoke a moderator
+    pub fn remove_moderator(ctx: Context<RemoveModerator>) -> Result<()> {
+        instructions::moderation::handler_remove_moderator(ctx)
+    }
+
+    /// Take an NFT's content down by pointing its metadata at the moderated
+    /// placeholder; transfers are not blocked
+    pub fn freeze_nft(ctx: Context<FreezeNft>, reason_code: u16) -> Result<()> {
+        instructions::moderation::handler_freeze_nft(ctx, reason_code)
+    }
+
+    /// Restore the metadata of an NFT frozen by moderation
+    pub fn thaw_nft(ctx: Context<ThawNft>, reason_code: u16) -> Result<()> {
+        instructions::moderation::handler_thaw_nft(ctx, reason_code)
+    }
+
+    /// Report an NFT, staking a SOL bond
+    pub fn report_nft(ctx: Context<ReportNft>, reason_code: u16) -> Result<()> {
+        instructions::moderation::handler_report_nft(ctx, reason_code)
+    }
+
+    /// Uphold (refund the bond, optionally freezing the NFT) or dismiss (slash
+    /// the bond to the treasury) a report
+    pub fn resolve_report(ctx: Context<ResolveReport>, upheld: bool) -> Result<()> {
+        instructions::moderation::handler_resolve_report(ctx, upheld)
+    }
+
+    /// Set the Sacred Waste Pit address
+    pub fn set_sacred_waste_pit(ctx: Context<SetSacredWastePit>, pit: Pubkey) -> Result<()> {
+        instructions::admin::handler_set_pit(ctx, pit)
+    }
+
+    /// Set (or clear) the backend key that approves free-form mint URIs
+    pub fn set_backend_signer(
+        ctx: Context<SetBackendSigner>,
+        backend_signer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::admin::handler_set_backend_signer(ctx, backend_signer)
+    }
+
+    /// Set (or clear) the VRF oracle program for the randomness mint path
+    pub fn set_vrf_oracle(ctx: Context<SetVrfOracle>, vrf_oracle: Option<Pubkey>) -> Result<()> {
+        instructions::admin::handler_set_vrf_oracle(ctx, vrf_oracle)
+    }
+
+    /// Replace the prefixes free-form mint URIs must start with
+    pub fn set_allowed_uri_prefixes(
+        ctx: Context<SetAllowedUriPrefixes>,
+        prefixes: Vec<String>,
+    ) -> Result<()> {
+        instructions::admin::handler_set_allowed_uri_prefixes(ctx, prefixes)
+    }
+
+    /// Set the basis-point share of the premium fee paid to template creators
+    pub fn set_template_share(ctx: Context<SetTemplateShare>, share_bps: u16) -> Result<()> {
+        instructions::admin::handler_set_template_share(ctx, share_bps)
+    }
+
+    /// Set the basis-point share of the premium fee paid to a remix's parent artist
+    pub fn set_parent_share(ctx: Context<SetParentShare>, share_bps: u16) -> Result<()> {
+        instructions::admin::handler_set_parent_share(ctx, share_bps)
+    }
+
     /// Update premium fee
     pub fn set_premium_fee(ctx: Context<SetPremiumFee>, fee: u64) -> Result<()> {
         instructions::admin::handler_set_premium_fee(ctx, fee)
@@ -6,11 +6,13 @@ use anchor_lang::prelude::*;
 pub struct CollectionConfig {
     /// Authority that can update the collection
     pub authority: Pubkey,
+    /// Collection slug, used as the PDA seed
+    pub slug: String,
     /// Collection name
     pub name: String,
     /// Collection symbol
     pub symbol: String,
-    /// Base URI for metadata
+    /// Base URI for metadata, token URIs are `{uri}{token_id}.json`
     pub uri: String,
     /// Treasury address for premium fees
     pub treasury: Pubkey,
@@ -20,17 +22,34 @@ pub struct CollectionConfig {
     pub total_minted: u64,
     /// Total tokens burned
     pub total_burned: u64,
+    /// Backend key that must co-sign mints with a free-form URI
+    pub backend_signer: Option<Pubkey>,
+    /// Prefixes a free-form URI must start with (e.g. `ipfs://`)
+    pub allowed_uri_prefixes: Vec<String>,
+    /// Sacred Waste Pit address (optional)
+    pub sacred_waste_pit: Option<Pubkey>,
+    /// VRF oracle program for the optional randomness mint path
+    pub vrf_oracle: Option<Pubkey>,
+    /// Share of `premium_fee` paid to the creator of a mint's template, in bps
+    pub template_share_bps: u16,
+    /// Share of `premium_fee` paid to the artist of a remix's parent, in bps
+    pub parent_share_bps: u16,
     /// Bump seed for PDA
     pub bump: u8,
 }
 
 impl CollectionConfig {
+    pub const MAX_SLUG_LEN: usize = 32;
     pub const MAX_NAME_LEN: usize = 32;
     pub const MAX_SYMBOL_LEN: usize = 10;
-    pub const MAX_URI_LEN: usize = 200;
+    /// Metaplex rejects metadata URIs longer than this
+    pub const MAX_URI_LEN: usize = mpl_token_metadata::MAX_URI_LENGTH;
+    pub const MAX_URI_PREFIXES: usize = 4;
+    pub const MAX_URI_PREFIX_LEN: usize = 64;
 
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        4 + Self::MAX_SLUG_LEN + // slug
         4 + Self::MAX_NAME_LEN + // name (string prefix + content)
         4 + Self::MAX_SYMBOL_LEN + // symbol
         4 + Self::MAX_URI_LEN + // uri
@@ -38,5 +57,879 @@ impl CollectionConfig {
         8 + // premium_fee
         8 + // total_minted
         8 + // total_burned
+        1 + 32 + // backend_signer (Option<Pubkey>)
+        4 + Self::MAX_URI_PREFIXES * (4 + Self::MAX_URI_PREFIX_LEN) + // allowed_uri_prefixes
+        1 + 32 + // sacred_waste_pit (Option<Pubkey>)
+        1 + 32 + // vrf_oracle (Option<Pubkey>)
+        2 + // template_share_bps
+        2 + // parent_share_bps
+        1; // bump
+}
+
+/// Per-token state for NFTs minted with `mint_with_premium`
+#[account]
+pub struct TokenState {
+    /// NFT mint
+    pub mint: Pubkey,
+    /// Collection the NFT was minted in
+    pub collection_config: Pubkey,
+    /// Sequential token ID within the collection
+    pub token_id: u64,
+    /// Wallet that minted the NFT, credited as its artist
+    pub artist: Pubkey,
+    /// Number of direct remixes of this NFT
+    pub remix_count: u64,
+    /// Hash of the rendered art, unique across the program
+    pub content_hash: [u8; 32],
+    /// Set once the placeholder metadata has been replaced
+    pub revealed: bool,
+    /// Backend's `sha256(nonce)` committed at mint time
+    pub commitment: Option<[u8; 32]>,
+    /// Future slot whose hash seeds the trait roll, `REVEAL_SLOT_DELAY`
+    /// slots after the mint
+    pub commit_slot: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TokenState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // collection_config
+        8 + // token_id
+        32 + // artist
+        8 + // remix_count
+        32 + // content_hash
+        1 + // revealed
+        1 + 32 + // commitment (Option<[u8; 32]>)
+        8 + // commit_slot
+        1; // bump
+
+    /// Slots between a commitment and the slot whose hash seeds the roll, so
+    /// the hash is unknown when the backend signs the mint
+    pub const REVEAL_SLOT_DELAY: u64 = 8;
+}
+
+/// Claims a content hash for the first NFT minted with it, so the same art
+/// cannot be minted twice
+#[account]
+pub struct ContentHash {
+    /// Hash of the rendered art (e.g. SHA-256 or a perceptual hash)
+    pub content_hash: [u8; 32],
+    /// NFT mint that claimed the hash
+    pub mint: Pubkey,
+    /// Wallet that minted it
+    pub artist: Pubkey,
+    /// Timestamp of the mint
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ContentHash {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // content_hash
+        32 + // mint
+        32 + // artist
+        8 + // created_at
+        1; // bump
+}
+
+/// Pending VRF randomness request for a minted token
+#[account]
+pub struct VrfRequest {
+    /// NFT mint the randomness is for
+    pub mint: Pubkey,
+    /// Oracle program expected to fulfil the request
+    pub oracle: Pubkey,
+    /// Seed passed to the oracle
+    pub seed: [u8; 32],
+    /// Timestamp of the request
+    pub requested_at: i64,
+    /// Set once the oracle callback has rolled the traits
+    pub fulfilled: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl VrfRequest {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // oracle
+        32 + // seed
+        8 + // requested_at
+        1 + // fulfilled
+        1; // bump
+}
+
+/// Registered meme template, credited when mints use it
+#[account]
+pub struct Template {
+    /// Hash identifying the template, used as the PDA seed
+    pub template_hash: [u8; 32],
+    /// Wallet that submitted the template
+    pub creator: Pubkey,
+    /// Template metadata / image URI
+    pub uri: String,
+    /// Number of mints that used this template
+    pub usage_count: u64,
+    /// Lamports paid to the creator from mints using this template
+    pub total_earned: u64,
+    /// Timestamp of registration
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Template {
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // template_hash
+        32 + // creator
+        4 + Self::MAX_URI_LEN + // uri
+        8 + // usage_count
+        8 + // total_earned
+        8 + // created_at
+        1; // bump
+}
+
+/// Template revenue earned by a creator across all their templates
+#[account]
+pub struct CreatorEarnings {
+    /// Template creator wallet
+    pub creator: Pubkey,
+    /// Total lamports earned from template revenue share
+    pub total_earned: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CreatorEarnings {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // total_earned
+        1; // bump
+}
+
+/// Links a remix to the NFT it was derived from. Original mints have none.
+#[account]
+pub struct Lineage {
+    /// Remix NFT mint
+    pub mint: Pubkey,
+    /// Mint the remix was derived from
+    pub parent: Pubkey,
+    /// Original (non-remix) mint at the top of the tree
+    pub root: Pubkey,
+    /// Remix generations below the root; a direct remix of an original is 1
+    pub depth: u32,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Lineage {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // parent
+        32 + // root
+        4 + // depth
+        1; // bump
+}
+
+/// Card aura, most to least common
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Aura {
+    Basic,
+    Shiny,
+    Holographic,
+    Cursed,
+    Ascended,
+}
+
+impl Aura {
+    pub const ALL: [Aura; 5] = [
+        Aura::Basic,
+        Aura::Shiny,
+        Aura::Holographic,
+        Aura::Cursed,
+        Aura::Ascended,
+    ];
+}
+
+/// Card class, most to least common
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CardClass {
+    Tech,
+    Finance,
+    Investments,
+    ItsComplicated,
+    ITradeJpegs,
+}
+
+impl CardClass {
+    pub const ALL: [CardClass; 5] = [
+        CardClass::Tech,
+        CardClass::Finance,
+        CardClass::Investments,
+        CardClass::ItsComplicated,
+        CardClass::ITradeJpegs,
+    ];
+}
+
+/// Traits rolled for a card at reveal. The tier rolled alongside them is
+/// kept in the card's `TokenTier`, the only record of a card's tier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CardTraits {
+    pub aura: Aura,
+    pub class: CardClass,
+}
+
+impl CardTraits {
+    pub const LEN: usize = 1 + 1;
+}
+
+/// Per-collection roll weights for each trait category, indexed like the
+/// enums' `ALL` arrays. Locked once the drop starts.
+#[account]
+pub struct TraitTable {
+    /// Collection the table applies to
+    pub collection_config: Pubkey,
+    pub aura_weights: [u16; 5],
+    pub tier_weights: [u16; 5],
+    pub class_weights: [u16; 5],
+    /// The table can no longer change from this timestamp (or the first mint)
+    pub drop_starts_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TraitTable {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection_config
+        2 * 5 + // aura_weights
+        2 * 5 + // tier_weights
+        2 * 5 + // class_weights
+        8 + // drop_starts_at
+        1; // bump
+
+    /// sha256 over the little-endian aura, tier and class weights
+    pub fn weights_hash(&self) -> [u8; 32] {
+        let bytes: Vec<u8> = self
+            .aura_weights
+            .iter()
+            .chain(&self.tier_weights)
+            .chain(&self.class_weights)
+            .flat_map(|weight| weight.to_le_bytes())
+            .collect();
+        anchor_lang::solana_program::hash::hash(&bytes).to_bytes()
+    }
+}
+
+/// Traits of a revealed token, readable by other programs
+#[account]
+pub struct TokenTraits {
+    /// NFT mint
+    pub mint: Pubkey,
+    /// Token ID (the collection's `total_minted` at mint time)
+    pub token_id: u64,
+    /// Rolled traits
+    pub traits: CardTraits,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TokenTraits {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        8 + // token_id
+        CardTraits::LEN + // traits
+        1; // bump
+}
+
+/// Record of a burned NFT for the gallery
+#[account]
+pub struct BurnedArt {
+    /// The artist who created and burned the NFT
+    pub artist: Pubkey,
+    /// Token URI / metadata
+    pub token_uri: String,
+    /// Timestamp of burn
+    pub burned_at: i64,
+    /// Original mint address
+    pub original_mint: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BurnedArt {
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // artist
+        4 + Self::MAX_URI_LEN + // token_uri
+        8 + // burned_at
+        32 + // original_mint
         1; // bump
 }
+
+/// How the pit stores burn history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PitStorageMode {
+    /// One `PitBurnRecord` account per burn
+    #[default]
+    Records,
+    /// Burns appended to fixed-size `PitBurnBucket` accounts. Entries keep the
+    /// reason and source collection but not the metadata URI, and burns with a
+    /// message are rejected
+    Buckets,
+}
+
+/// Why a burner sent their NFT to the pit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BurnReason {
+    Cringe,
+    Rugged,
+    Regret,
+    Sacrifice,
+}
+
+/// NFT held in escrow until its burn executes
+#[account]
+pub struct ScheduledBurn {
+    /// Owner who scheduled the burn
+    pub burner: Pubkey,
+    /// Escrowed NFT mint
+    pub mint: Pubkey,
+    /// Collection the NFT belongs to
+    pub collection_config: Pubkey,
+    /// The burn can be cancelled until, and executed from, this timestamp
+    pub executes_after: i64,
+    /// Burner's stated reason
+    pub reason: BurnReason,
+    /// Short memorial message (epitaph) left by the burner
+    pub message: Option<String>,
+    /// Bump seed for the escrow token account PDA
+    pub escrow_bump: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ScheduledBurn {
+    /// Cancel window a new pit starts with, in seconds
+    pub const DEFAULT_CANCEL_WINDOW: i64 = 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // burner
+        32 + // mint
+        32 + // collection_config
+        8 + // executes_after
+        1 + // reason
+        1 + 4 + PitBurnRecord::MAX_MESSAGE_LEN + // message (Option<String>)
+        1 + // escrow_bump
+        1; // bump
+}
+
+/// Sacred Waste Pit configuration
+#[account]
+#[derive(Default)]
+pub struct SacredWastePit {
+    /// Authority that can manage the pit
+    pub authority: Pubkey,
+    /// Total burns deposited
+    pub total_burns: u64,
+    /// Where new burns are stored
+    pub storage_mode: PitStorageMode,
+    /// Whether burners may close their burn records to reclaim rent
+    pub record_closing_enabled: bool,
+    /// Seconds a burn record must exist before it can be closed
+    pub archival_period: i64,
+    /// ID of the latest opened season (seasons are numbered from 1)
+    pub current_season: u64,
+    /// Whether `current_season` is still open
+    pub season_active: bool,
+    /// Whether `PitRewards` is initialized, so burns must pay the reward
+    pub rewards_enabled: bool,
+    /// Seconds a scheduled burn can still be cancelled
+    pub cancel_window: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SacredWastePit {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        8 + // total_burns
+        1 + // storage_mode
+        1 + // record_closing_enabled
+        8 + // archival_period
+        8 + // current_season
+        1 + // season_active
+        1 + // rewards_enabled
+        8 + // cancel_window
+        1; // bump
+}
+
+/// Individual burn record in the Sacred Waste Pit
+#[account]
+pub struct PitBurnRecord {
+    /// The burner address
+    pub burner: Pubkey,
+    /// Metadata string
+    pub metadata: String,
+    /// Timestamp of burn
+    pub timestamp: i64,
+    /// Burn ID (sequential)
+    pub burn_id: u64,
+    /// Collection the burned NFT came from: our collection config, or the
+    /// verified Metaplex collection mint for foreign burns
+    pub source_collection: Option<Pubkey>,
+    /// Burner's stated reason, set for `burn_to_waste` burns
+    pub reason: Option<BurnReason>,
+    /// Short memorial message (epitaph) left by the burner
+    pub message: Option<String>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PitBurnRecord {
+    pub const MAX_METADATA_LEN: usize = 200;
+    pub const MAX_MESSAGE_LEN: usize = 140;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // burner
+        4 + Self::MAX_METADATA_LEN + // metadata
+        8 + // timestamp
+        8 + // burn_id
+        1 + 32 + // source_collection (Option<Pubkey>)
+        1 + 1 + // reason (Option<BurnReason>)
+        1 + 4 + Self::MAX_MESSAGE_LEN + // message (Option<String>)
+        1; // bump
+}
+
+/// Per-address burn counter for the pit
+#[account]
+pub struct BurnerStats {
+    /// The burner address
+    pub burner: Pubkey,
+    /// Number of burns by this address
+    pub burn_count: u64,
+    /// Total reward tokens paid out for burns
+    pub total_rewards: u64,
+    /// Soul-bound burn certificates minted to this address
+    pub certificates_minted: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BurnerStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // burner
+        8 + // burn_count
+        8 + // total_rewards
+        8 + // certificates_minted
+        1; // bump
+}
+
+/// One ranked burner on the leaderboard
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LeaderboardEntry {
+    pub burner: Pubkey,
+    pub burn_count: u64,
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + 8;
+}
+
+/// Top burners by `burn_count`, highest first
+#[account]
+pub struct BurnLeaderboard {
+    /// Ranked entries, at most `MAX_ENTRIES`
+    pub entries: Vec<LeaderboardEntry>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BurnLeaderboard {
+    pub const MAX_ENTRIES: usize = 10;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + Self::MAX_ENTRIES * LeaderboardEntry::LEN + // entries
+        1; // bump
+}
+
+/// A competitive burn season; standings are frozen once it closes
+#[account]
+pub struct Season {
+    /// Sequential season ID
+    pub season_id: u64,
+    /// Burns count toward the season from this timestamp
+    pub start_ts: i64,
+    /// Burns stop counting at this timestamp
+    pub end_ts: i64,
+    /// Burns counted in this season
+    pub total_burns: u64,
+    /// Top burners of the season, highest first
+    pub standings: Vec<LeaderboardEntry>,
+    /// Set when the authority closes the season
+    pub closed_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Season {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // season_id
+        8 + // start_ts
+        8 + // end_ts
+        8 + // total_burns
+        4 + BurnLeaderboard::MAX_ENTRIES * LeaderboardEntry::LEN + // standings
+        1 + 8 + // closed_at
+        1; // bump
+}
+
+/// Per-address burn counter for a single season
+#[account]
+pub struct SeasonBurnerStats {
+    /// Season the burns belong to
+    pub season_id: u64,
+    /// The burner address
+    pub burner: Pubkey,
+    /// Number of burns by this address in the season
+    pub burn_count: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SeasonBurnerStats {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // season_id
+        32 + // burner
+        8 + // burn_count
+        1; // bump
+}
+
+/// Program allowed to deposit burns into the pit via CPI
+#[account]
+pub struct AuthorizedProgram {
+    /// The authorized program address
+    pub program_id: Pubkey,
+    /// Whether it's currently authorized
+    pub is_authorized: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AuthorizedProgram {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // program_id
+        1 + // is_authorized
+        1; // bump
+}
+
+/// Foreign Metaplex collection whose verified NFTs may be burned into the pit
+#[account]
+pub struct AllowedCollection {
+    /// Verified collection mint address
+    pub collection_mint: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AllowedCollection {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection_mint
+        1; // bump
+}
+
+/// Wallet allowed to moderate a collection's NFTs
+#[account]
+pub struct Moderator {
+    /// Collection the moderator acts for
+    pub collection_config: Pubkey,
+    /// Moderator wallet
+    pub moderator: Pubkey,
+    /// Moderation actions taken, used to seed the next `ModerationAction`
+    pub action_count: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Moderator {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection_config
+        32 + // moderator
+        8 + // action_count
+        1; // bump
+}
+
+/// Kind of moderation applied to an NFT
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ModerationKind {
+    Freeze,
+    Thaw,
+}
+
+/// Audit record of a single freeze or thaw
+#[account]
+pub struct ModerationAction {
+    /// Collection the action was taken for
+    pub collection_config: Pubkey,
+    /// Moderator wallet that acted
+    pub moderator: Pubkey,
+    /// NFT mint
+    pub mint: Pubkey,
+    /// Freeze or thaw
+    pub kind: ModerationKind,
+    /// Moderator-supplied reason code
+    pub reason_code: u16,
+    /// Timestamp of the action
+    pub timestamp: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ModerationAction {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection_config
+        32 + // moderator
+        32 + // mint
+        1 + // kind
+        2 + // reason_code
+        8 + // timestamp
+        1; // bump
+}
+
+/// Takedown of a frozen NFT: its metadata points at the collection's
+/// moderated placeholder until a thaw restores `original_uri`
+#[account]
+pub struct FrozenNft {
+    /// Collection the NFT belongs to
+    pub collection_config: Pubkey,
+    /// NFT mint
+    pub mint: Pubkey,
+    /// Metadata URI replaced by the placeholder
+    pub original_uri: String,
+    /// Timestamp of the freeze
+    pub frozen_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FrozenNft {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection_config
+        32 + // mint
+        4 + CollectionConfig::MAX_URI_LEN + // original_uri
+        8 + // frozen_at
+        1; // bump
+}
+
+/// Bonded report of an NFT, keyed by mint and reporter
+#[account]
+pub struct Report {
+    /// Collection the reported NFT belongs to
+    pub collection_config: Pubkey,
+    /// Reported NFT mint
+    pub mint: Pubkey,
+    /// Reporter wallet, refunded when the report is upheld
+    pub reporter: Pubkey,
+    /// Reporter-supplied reason code
+    pub reason_code: u16,
+    /// Lamports staked on top of rent, slashed if the report is dismissed
+    pub bond: u64,
+    /// Timestamp of the report
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Report {
+    /// Bond staked by every report (0.01 SOL)
+    pub const BOND_LAMPORTS: u64 = 10_000_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection_config
+        32 + // mint
+        32 + // reporter
+        2 + // reason_code
+        8 + // bond
+        8 + // created_at
+        1; // bump
+}
+
+/// Reward multiplier applied once a burner reaches `min_burn_count` burns
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RewardTier {
+    /// Burn count (including the current burn) at which this tier applies
+    pub min_burn_count: u64,
+    /// Multiplier on the base reward in basis points (10_000 = 1x)
+    pub multiplier_bps: u16,
+}
+
+impl RewardTier {
+    pub const LEN: usize = 8 + // min_burn_count
+        2; // multiplier_bps
+}
+
+/// Burn-to-earn configuration for the Sacred Waste Pit
+#[account]
+#[derive(Default)]
+pub struct PitRewards {
+    /// Reward token mint
+    pub reward_mint: Pubkey,
+    /// Token account PDA holding rewards, owned by the pit
+    pub vault: Pubkey,
+    /// Base reward paid per burn
+    pub reward_per_burn: u64,
+    /// Multiplier tiers by burn count, ascending by `min_burn_count`
+    pub tiers: Vec<RewardTier>,
+    /// Length of an emission epoch in seconds
+    pub epoch_duration: i64,
+    /// Maximum rewards paid out per epoch
+    pub epoch_cap: u64,
+    /// Start timestamp of the current epoch
+    pub epoch_start: i64,
+    /// Rewards paid out in the current epoch
+    pub epoch_emitted: u64,
+    /// Rewards paid out over all epochs
+    pub total_emitted: u64,
+    /// Bump seed for the vault PDA
+    pub vault_bump: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PitRewards {
+    pub const MAX_TIERS: usize = 4;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // reward_mint
+        32 + // vault
+        8 + // reward_per_burn
+        4 + Self::MAX_TIERS * RewardTier::LEN + // tiers
+        8 + // epoch_duration
+        8 + // epoch_cap
+        8 + // epoch_start
+        8 + // epoch_emitted
+        8 + // total_emitted
+        1 + // vault_bump
+        1; // bump
+}
+
+/// Card tier, lowest to highest
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardTier {
+    #[default]
+    Shitpost,
+    Mid,
+    Classic,
+    Iconic,
+    Fire,
+}
+
+impl CardTier {
+    pub const ALL: [CardTier; 5] = [
+        CardTier::Shitpost,
+        CardTier::Mid,
+        CardTier::Classic,
+        CardTier::Iconic,
+        CardTier::Fire,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardTier::Shitpost => "Shitpost",
+            CardTier::Mid => "Mid",
+            CardTier::Classic => "Classic",
+            CardTier::Iconic => "Iconic",
+            CardTier::Fire => "Fire",
+        }
+    }
+}
+
+/// On-chain tier of a card, rolled at reveal or set when an upgrade mints it.
+/// Cards without a record are `CardTier::Shitpost`
+#[account]
+pub struct TokenTier {
+    /// Card mint address
+    pub mint: Pubkey,
+    /// Card tier
+    pub tier: CardTier,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TokenTier {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        1 + // tier
+        1; // bump
+}
+
+/// Burn recipe: `input_count` cards of `input_tier` upgrade to one `output_tier` card
+#[account]
+pub struct UpgradeRecipe {
+    /// Collection config the recipe belongs to
+    pub collection: Pubkey,
+    /// Tier of the cards burned
+    pub input_tier: CardTier,
+    /// Number of cards burned, 0 disables the recipe
+    pub input_count: u8,
+    /// Tier of the card minted
+    pub output_tier: CardTier,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl UpgradeRecipe {
+    pub const MAX_INPUTS: u8 = 5;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection
+        1 + // input_tier
+        1 + // input_count
+        1 + // output_tier
+        1; // bump
+}
+
+/// Compact burn entry stored in a `PitBurnBucket`
+#[zero_copy]
+#[derive(Default)]
+pub struct PitBucketEntry {
+    /// The burner address
+    pub burner: Pubkey,
+    /// Burned mint, default for burns deposited by other programs
+    pub mint: Pubkey,
+    /// Collection the burned NFT came from, default when unknown
+    pub source_collection: Pubkey,
+    /// Burn ID (sequential)
+    pub burn_id: u64,
+    /// Timestamp of burn
+    pub timestamp: i64,
+    /// `BurnReason` index plus one, 0 when no reason was given
+    pub reason: u8,
+    /// Keeps the entry free of implicit padding
+    pub _padding: [u8; 7],
+}
+
+/// Fixed-size page of pit burns, seeded by bucket index
+#[account(zero_copy)]
+pub struct PitBurnBucket {
+    /// Index of this bucket, covers burn IDs `[index * CAPACITY, (index + 1) * CAPACITY)`
+    pub bucket_index: u64,
+    /// Number of entries written; entries are slotted by `burn_id % CAPACITY`
+    pub len: u64,
+    /// Account that paid rent, refunded when the bucket is closed
+    pub payer: Pubkey,
+    /// Burn entries, empty slots have a default `burner`
+    pub entries: [PitBucketEntry; 80],
+}
+
+impl PitBurnBucket {
+    /// Entries per bucket, keeping the account under the 10 KiB that `init`
+    /// can allocate through a CPI
+    pub const CAPACITY: u64 = 80;
+
+    pub const LEN: usize = 8 + std::mem::size_of::<PitBurnBucket>();
+}
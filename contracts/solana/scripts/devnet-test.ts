@@ -7,6 +7,7 @@ import {
   getAssociatedTokenAddress
 } from "@solana/spl-token";
 import * as fs from "fs";
+import { createHash } from "crypto";
 
 // Metaplex Token Metadata Program
 const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -14,6 +15,7 @@ const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzj
 // Load the IDL
 const idl = JSON.parse(fs.readFileSync("./target/idl/shitpost_pro.json", "utf-8"));
 const PROGRAM_ID = new PublicKey("7F6SJmYgF8iEF9DQmpDUuboTRs4qYt5hr27TcXCuykDo");
+const COLLECTION_SLUG = process.env.COLLECTION_SLUG || "classic-memes";
 
 async function main() {
   // Setup connection
@@ -45,7 +47,7 @@ async function main() {
 
   // Derive PDAs
   const [collectionConfig] = PublicKey.findProgramAddressSync(
-    [Buffer.from("collection_config")],
+    [Buffer.from("collection_config"), Buffer.from(COLLECTION_SLUG)],
     PROGRAM_ID
   );
   console.log(`Collection Config PDA: ${collectionConfig.toBase58()}\n`);
@@ -77,6 +79,7 @@ async function main() {
       console.log("Initializing new collection...");
       const tx = await program.methods
         .initialize(
+          COLLECTION_SLUG,          // slug
           "Shitpost Desktop",      // name
           "SHIT",                   // symbol
           "https://shitpost.pro",   // uri
@@ -110,6 +113,43 @@ async function main() {
     console.log("");
   }
 
+  // ============================================================
+  // STEP 2b: Approve free-form URIs
+  // ============================================================
+  console.log("=".repeat(60));
+  console.log("STEP 2b: Configure backend signer and URI prefixes");
+  console.log("=".repeat(60));
+
+  try {
+    // The test wallet co-signs free-form URIs in place of the backend
+    const signerTx = await program.methods
+      .setBackendSigner(wallet.publicKey)
+      .accounts({
+        authority: wallet.publicKey,
+        collectionConfig: collectionConfig,
+      })
+      .signers([wallet])
+      .rpc();
+    console.log("Set Backend Signer TX:", signerTx);
+
+    const prefixTx = await program.methods
+      .setAllowedUriPrefixes(["ipfs://"])
+      .accounts({
+        authority: wallet.publicKey,
+        collectionConfig: collectionConfig,
+      })
+      .signers([wallet])
+      .rpc();
+    console.log("Set URI Prefixes TX:", prefixTx);
+
+    console.log("✅ Configure URIs: SUCCESS\n");
+  } catch (e: any) {
+    console.log("❌ Configure URIs: FAILED");
+    console.log("Error:", e.message);
+    if (e.logs) console.log("Logs:", e.logs.slice(-5));
+    console.log("");
+  }
+
   // ============================================================
   // STEP 3: Mint with Premium
   // ============================================================
@@ -152,15 +192,33 @@ async function main() {
       TOKEN_METADATA_PROGRAM_ID
     );
 
+    // Derive per-token state PDA
+    const [tokenState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_state"), mint.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    // Claim a unique content hash per run so reruns don't hit DuplicateContent
+    const contentHash = createHash("sha256")
+      .update(mint.publicKey.toBuffer())
+      .digest();
+    const [contentHashRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("content_hash"), contentHash],
+      PROGRAM_ID
+    );
+
     console.log("Minting NFT...");
     const tx = await program.methods
-      .mintWithPremium("ipfs://QmTestDevnetUri123")
+      .mintWithPremium("ipfs://QmTestDevnetUri123", null, [...contentHash])
       .accounts({
         minter: wallet.publicKey,
         collectionConfig: collectionConfig,
+        backendSigner: wallet.publicKey,
         treasury: treasury,
         mint: mint.publicKey,
         tokenAccount: tokenAccount,
+        tokenState: tokenState,
+        contentHashRecord: contentHashRecord,
         metadata: metadata,
         masterEdition: masterEdition,
         tokenProgram: TOKEN_PROGRAM_ID,
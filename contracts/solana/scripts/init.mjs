@@ -15,6 +15,7 @@ const walletKeypair = Keypair.fromSecretKey(
 
 // Program ID
 const PROGRAM_ID = new PublicKey('62gJC6oneEykVGdAq7Lr2x5bw33B3CnmHVHeeCxkZ7yJ');
+const COLLECTION_SLUG = process.env.COLLECTION_SLUG || 'classic-memes';
 
 // Load IDL
 const idlPath = path.join(__dirname, '../target/idl/shitpost_pro.json');
@@ -40,7 +41,7 @@ async function main() {
 
   // Derive PDAs
   const [collectionConfig] = PublicKey.findProgramAddressSync(
-    [Buffer.from('collection_config')],
+    [Buffer.from('collection_config'), Buffer.from(COLLECTION_SLUG)],
     PROGRAM_ID
   );
 
@@ -70,6 +71,7 @@ async function main() {
   try {
     const tx = await program.methods
       .initialize(
+        COLLECTION_SLUG,                  // slug
         'ShitpostPro',                    // name
         'SHIT',                           // symbol
         'https://shitpost.pro',           // uri
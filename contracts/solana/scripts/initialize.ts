@@ -3,6 +3,8 @@ import { Program } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
 import { ShitpostPro } from "../target/types/shitpost_pro";
 
+const COLLECTION_SLUG = process.env.COLLECTION_SLUG || "classic-memes";
+
 async function main() {
   // Configure the client
   const provider = anchor.AnchorProvider.env();
@@ -15,7 +17,7 @@ async function main() {
 
   // Derive PDAs
   const [collectionConfig] = PublicKey.findProgramAddressSync(
-    [Buffer.from("collection_config")],
+    [Buffer.from("collection_config"), Buffer.from(COLLECTION_SLUG)],
     program.programId
   );
 
@@ -44,6 +46,7 @@ async function main() {
   try {
     const tx = await program.methods
       .initialize(
+        COLLECTION_SLUG,          // slug
         "ShitpostPro",           // name
         "SHIT",                   // symbol
         provider.wallet.publicKey // treasury
@@ -3,6 +3,8 @@ import { Program } from "@coral-xyz/anchor";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { ShitpostPro } from "../target/types/shitpost_pro";
 
+const COLLECTION_SLUG = "classic-memes";
+
 describe("initialize", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -15,7 +17,7 @@ describe("initialize", () => {
 
     // Derive PDAs
     const [collectionConfig] = PublicKey.findProgramAddressSync(
-      [Buffer.from("collection_config")],
+      [Buffer.from("collection_config"), Buffer.from(COLLECTION_SLUG)],
       program.programId
     );
 
@@ -44,6 +46,7 @@ describe("initialize", () => {
     try {
       const tx = await program.methods
         .initialize(
+          COLLECTION_SLUG,
           "ShitpostPro",
           "SHIT",
           provider.wallet.publicKey
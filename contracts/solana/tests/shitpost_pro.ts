@@ -2,7 +2,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ShitpostPro } from "../target/types/shitpost_pro";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import {
+  ComputeBudgetProgram,
   Keypair,
   PublicKey,
   SystemProgram,
@@ -12,6 +14,12 @@ import {
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
+  createMint,
+  createAssociatedTokenAccount,
+  getAccount,
+  transfer,
+  mintTo,
+  createAssociatedTokenAccountIdempotentInstruction,
 } from "@solana/spl-token";
 
 describe("shitpost_pro", () => {
@@ -22,6 +30,14 @@ describe("shitpost_pro", () => {
 
   const authority = provider.wallet;
   const treasury = Keypair.generate();
+  const slug = "classic-memes";
+
+  const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+    "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+  );
+
+  // PitBurnBucket::CAPACITY
+  const BUCKET_CAPACITY = 80;
 
   // PDAs
   let collectionConfigPda: PublicKey;
@@ -30,7 +46,7 @@ describe("shitpost_pro", () => {
   before(async () => {
     // Derive PDAs
     [collectionConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("collection_config")],
+      [Buffer.from("collection_config"), Buffer.from(slug)],
       program.programId
     );
 
@@ -40,6 +56,558 @@ describe("shitpost_pro", () => {
     );
   });
 
+  const metadataPdaFor = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+
+  const editionPdaFor = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("metadata"),
+        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+        mint.toBuffer(),
+        Buffer.from("edition"),
+      ],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+
+  const tokenTraitsPdaFor = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("token_traits"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const tokenTierPdaFor = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("token_tier"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const vrfRequestPdaFor = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("vrf_request"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const lineagePdaFor = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("lineage"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const creatorEarningsPdaFor = (creator: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_earnings"), creator.toBuffer()],
+      program.programId
+    )[0];
+
+  // Mint an NFT (classic-memes by default) to the wallet, or to `minter`, with
+  // `mint_with_premium`, using a base-URI metadata URI unless a free-form
+  // `uri` is given and (by default) a random content hash. With `vrfOracle`
+  // the traits are requested from it; with `template` the mint is credited
+  // to that template, and with `parent` it is recorded as a remix of it.
+  const mintNft = async (
+    options: {
+      minter?: Keypair;
+      template?: PublicKey;
+      parent?: PublicKey;
+      uri?: string;
+      contentHash?: Buffer;
+      commitment?: number[];
+      backendSigner?: Keypair;
+      collectionConfig?: PublicKey;
+      vrfOracle?: PublicKey;
+    } = {}
+  ) => {
+    const collectionConfig = options.collectionConfig ?? collectionConfigPda;
+    const mint = Keypair.generate();
+    const contentHash = options.contentHash ?? Keypair.generate().publicKey.toBuffer();
+    const minter = options.minter?.publicKey ?? authority.publicKey;
+    const tokenAccount = getAssociatedTokenAddressSync(mint.publicKey, minter);
+    const [tokenState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_state"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [contentHashRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("content_hash"), contentHash],
+      program.programId
+    );
+
+    // Treasuries start empty; top them up so the fee clears the rent minimum
+    const config = await program.account.collectionConfig.fetch(collectionConfig);
+    const preInstructions = [ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })];
+    if ((await provider.connection.getBalance(config.treasury)) === 0) {
+      preInstructions.push(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: config.treasury,
+          lamports: anchor.web3.LAMPORTS_PER_SOL,
+        })
+      );
+    }
+
+    const template = options.template
+      ? await program.account.template.fetch(options.template)
+      : null;
+    const parentTokenState = options.parent
+      ? PublicKey.findProgramAddressSync(
+          [Buffer.from("token_state"), options.parent.toBuffer()],
+          program.programId
+        )[0]
+      : null;
+    const parent = options.parent
+      ? await program.account.tokenState.fetch(parentTokenState)
+      : null;
+    const lineage = options.parent ? lineagePdaFor(mint.publicKey) : null;
+
+    await program.methods
+      .mintWithPremium(options.uri ?? null, options.commitment ?? null, [...contentHash])
+      .accounts({
+        minter,
+        collectionConfig,
+        backendSigner: options.backendSigner?.publicKey ?? null,
+        treasury: config.treasury,
+        mint: mint.publicKey,
+        tokenAccount,
+        tokenState,
+        contentHashRecord,
+        metadata: metadataPdaFor(mint.publicKey),
+        masterEdition: editionPdaFor(mint.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        vrfRequest: options.vrfOracle ? vrfRequestPdaFor(mint.publicKey) : null,
+        vrfOracle: options.vrfOracle ?? null,
+        template: options.template ?? null,
+        templateCreator: template?.creator ?? null,
+        creatorEarnings: template ? creatorEarningsPdaFor(template.creator) : null,
+        parentTokenState,
+        parentLineage: options.parent ? lineagePdaFor(options.parent) : null,
+        lineage,
+        parentArtist: parent?.artist ?? null,
+      })
+      .preInstructions(preInstructions)
+      .signers([mint, options.backendSigner, options.minter].filter(Boolean))
+      .rpc();
+
+    return { mint: mint.publicKey, tokenAccount, tokenState, contentHash, lineage };
+  };
+
+  // A fresh keypair holding 1 SOL
+  const fundedKeypair = async () => {
+    const keypair = Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: keypair.publicKey,
+          lamports: anchor.web3.LAMPORTS_PER_SOL,
+        })
+      )
+    );
+    return keypair;
+  };
+
+  // Decode the name and URI of a Token Metadata account
+  const readMetadata = async (mint: PublicKey) => {
+    const { data } = await provider.connection.getAccountInfo(metadataPdaFor(mint));
+    let offset = 1 + 32 + 32;
+    const readString = () => {
+      const len = data.readUInt32LE(offset);
+      const value = data.subarray(offset + 4, offset + 4 + len).toString().replace(/\0+$/, "");
+      offset += 4 + len;
+      return value;
+    };
+    const name = readString();
+    const symbol = readString();
+    const uri = readString();
+    return { name, symbol, uri };
+  };
+
+  // Mint a master edition NFT outside the program through raw Token Metadata
+  // instructions, verified as a member of `collection` when given
+  const mintForeignNft = async (collection?: PublicKey) => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const mint = await createMint(provider.connection, payer, payer.publicKey, payer.publicKey, 0);
+    const tokenAccount = await createAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      mint,
+      payer.publicKey
+    );
+    await mintTo(provider.connection, payer, mint, tokenAccount, payer, 1);
+
+    const borshString = (value: string) => {
+      const len = Buffer.alloc(4);
+      len.writeUInt32LE(Buffer.byteLength(value));
+      return Buffer.concat([len, Buffer.from(value)]);
+    };
+    const account = (pubkey: PublicKey, isSigner: boolean, isWritable: boolean) => ({
+      pubkey,
+      isSigner,
+      isWritable,
+    });
+    const instruction = (keys, data: Buffer) =>
+      new anchor.web3.TransactionInstruction({
+        programId: TOKEN_METADATA_PROGRAM_ID,
+        keys,
+        data,
+      });
+
+    const tx = new anchor.web3.Transaction().add(
+      // CreateMetadataAccountV3: no creators or uses, mutable, unsized
+      instruction(
+        [
+          account(metadataPdaFor(mint), false, true),
+          account(mint, false, false),
+          account(payer.publicKey, true, false),
+          account(payer.publicKey, true, true),
+          account(payer.publicKey, true, false),
+          account(SystemProgram.programId, false, false),
+          account(SYSVAR_RENT_PUBKEY, false, false),
+        ],
+        Buffer.concat([
+          Buffer.from([33]),
+          borshString("Foreign Meme"),
+          borshString("FRGN"),
+          borshString("https://example.com/foreign.json"),
+          Buffer.from([0, 0, 0]),
+          collection
+            ? Buffer.concat([Buffer.from([1, 0]), collection.toBuffer()])
+            : Buffer.from([0]),
+          Buffer.from([0, 1, 0]),
+        ])
+      ),
+      // CreateMasterEditionV3 with a max supply of zero
+      instruction(
+        [
+          account(editionPdaFor(mint), false, true),
+          account(mint, false, true),
+          account(payer.publicKey, true, false),
+          account(payer.publicKey, true, false),
+          account(payer.publicKey, true, true),
+          account(metadataPdaFor(mint), false, true),
+          account(TOKEN_PROGRAM_ID, false, false),
+          account(SystemProgram.programId, false, false),
+          account(SYSVAR_RENT_PUBKEY, false, false),
+        ],
+        Buffer.from([17, 1, 0, 0, 0, 0, 0, 0, 0, 0])
+      )
+    );
+    if (collection) {
+      // VerifyCollection, signed by the collection's update authority
+      tx.add(
+        instruction(
+          [
+            account(metadataPdaFor(mint), false, true),
+            account(payer.publicKey, true, true),
+            account(payer.publicKey, true, true),
+            account(collection, false, false),
+            account(metadataPdaFor(collection), false, true),
+            account(editionPdaFor(collection), false, false),
+          ],
+          Buffer.from([18])
+        )
+      );
+    }
+    await provider.sendAndConfirm(tx);
+
+    return { mint, tokenAccount };
+  };
+
+  // Pit bookkeeping accounts for a burn by `burner`: the record or bucket the
+  // storage mode calls for, stats, leaderboard, and the season and reward
+  // accounts while those are live (unless omitted to test that they are required)
+  const pitBurnAccounts = async (
+    burner: PublicKey,
+    options: { omitSeason?: boolean; omitRewards?: boolean } = {}
+  ) => {
+    const pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+    const buckets = "buckets" in pit.storageMode;
+    const withSeason = pit.seasonActive && !options.omitSeason;
+    const withRewards = pit.rewardsEnabled && !options.omitRewards;
+    const seasonId = pit.currentSeason.toArrayLike(Buffer, "le", 8);
+    const [pitBurnRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pit_burn"), pit.totalBurns.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [pitBucket] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pit_bucket"),
+        pit.totalBurns.divn(BUCKET_CAPACITY).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [pitRewardsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pit_rewards")],
+      program.programId
+    );
+    const rewards = pit.rewardsEnabled
+      ? await program.account.pitRewards.fetch(pitRewardsPda)
+      : null;
+    const burnerRewardAccount = rewards
+      ? getAssociatedTokenAddressSync(rewards.rewardMint, burner)
+      : null;
+
+    return {
+      burnId: pit.totalBurns,
+      pitBurnRecord,
+      pitBucket,
+      burnerRewardAccount,
+      preInstructions: rewards
+        ? [
+            createAssociatedTokenAccountIdempotentInstruction(
+              authority.publicKey,
+              burnerRewardAccount,
+              burner,
+              rewards.rewardMint
+            ),
+          ]
+        : [],
+      accounts: {
+        pitBurnRecord: buckets ? null : pitBurnRecord,
+        pitBucket: buckets ? pitBucket : null,
+        burnerStats: PublicKey.findProgramAddressSync(
+          [Buffer.from("burner_stats"), burner.toBuffer()],
+          program.programId
+        )[0],
+        burnLeaderboard: PublicKey.findProgramAddressSync(
+          [Buffer.from("burn_leaderboard")],
+          program.programId
+        )[0],
+        season: withSeason
+          ? PublicKey.findProgramAddressSync(
+              [Buffer.from("season"), seasonId],
+              program.programId
+            )[0]
+          : null,
+        seasonStats: withSeason
+          ? PublicKey.findProgramAddressSync(
+              [Buffer.from("season_stats"), seasonId, burner.toBuffer()],
+              program.programId
+            )[0]
+          : null,
+        pitRewards: withRewards ? pitRewardsPda : null,
+        rewardVault: withRewards ? rewards.vault : null,
+        burnerRewardAccount: withRewards ? burnerRewardAccount : null,
+      },
+    };
+  };
+
+  // Burn an NFT minted by `mintNft` to the pit, optionally minting its burn
+  // certificate
+  const burnToWaste = async (
+    mint: PublicKey,
+    tokenAccount: PublicKey,
+    options: {
+      reason?: object;
+      message?: string;
+      certificate?: boolean;
+      omitSeason?: boolean;
+    } = {}
+  ) => {
+    const pitBurn = await pitBurnAccounts(authority.publicKey, options);
+    const [certificateMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("burn_certificate"), mint.toBuffer()],
+      program.programId
+    );
+    const certificateTokenAccount = getAssociatedTokenAddressSync(
+      certificateMint,
+      authority.publicKey
+    );
+
+    await program.methods
+      .burnToWaste(options.reason ?? { cringe: {} }, options.message ?? null)
+      .accounts({
+        burner: authority.publicKey,
+        collectionConfig: collectionConfigPda,
+        sacredWastePit: sacredWastePitPda,
+        mint,
+        tokenAccount,
+        burnedArt: PublicKey.findProgramAddressSync(
+          [Buffer.from("burned_art"), mint.toBuffer()],
+          program.programId
+        )[0],
+        ...pitBurn.accounts,
+        metadata: metadataPdaFor(mint),
+        certificateMint: options.certificate ? certificateMint : null,
+        certificateTokenAccount: options.certificate ? certificateTokenAccount : null,
+        certificateMetadata: options.certificate ? metadataPdaFor(certificateMint) : null,
+        tokenMetadataProgram: options.certificate ? TOKEN_METADATA_PROGRAM_ID : null,
+        associatedTokenProgram: options.certificate ? ASSOCIATED_TOKEN_PROGRAM_ID : null,
+        rent: options.certificate ? SYSVAR_RENT_PUBKEY : null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+        ...pitBurn.preInstructions,
+      ])
+      .rpc();
+
+    return {
+      burnId: pitBurn.burnId,
+      pitBurnRecord: pitBurn.pitBurnRecord,
+      pitBucket: pitBurn.pitBucket,
+      certificateMint,
+      certificateTokenAccount,
+    };
+  };
+
+  // Deposit a burn for `authority` through `callerProgram`'s relay, with the
+  // pit bookkeeping accounts of the current pit state
+  const relayDeposit = async (
+    callerProgram: PublicKey,
+    metadata: string,
+    options: { reason?: object; message?: string } = {}
+  ) => {
+    const mockPitCaller = anchor.workspace.MockPitCaller as Program;
+    const [callerAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pit_caller")],
+      callerProgram
+    );
+    const [authorizedProgramPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("authorized_program"), callerProgram.toBuffer()],
+      program.programId
+    );
+    const pitBurn = await pitBurnAccounts(authority.publicKey);
+
+    const ix = await program.methods
+      .depositBurn(authority.publicKey, metadata, options.reason ?? null, options.message ?? null)
+      .accounts({
+        payer: authority.publicKey,
+        sacredWastePit: sacredWastePitPda,
+        callerProgram,
+        callerAuthority,
+        authorizedProgram: authorizedProgramPda,
+        ...pitBurn.accounts,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const signature = await mockPitCaller.methods
+      .relay(ix.data)
+      .accounts({
+        pitCaller: PublicKey.findProgramAddressSync(
+          [Buffer.from("pit_caller")],
+          mockPitCaller.programId
+        )[0],
+        targetProgram: program.programId,
+      })
+      .remainingAccounts(
+        ix.keys.map((key) =>
+          key.pubkey.equals(callerAuthority) ? { ...key, isSigner: false } : key
+        )
+      )
+      .preInstructions(pitBurn.preInstructions)
+      .rpc({ commitment: "confirmed" });
+
+    return { recordPda: pitBurn.pitBurnRecord, signature, ...pitBurn };
+  };
+
+  const setPitStorageMode = (mode: object) =>
+    program.methods
+      .setPitStorageMode(mode)
+      .accounts({
+        authority: authority.publicKey,
+        sacredWastePit: sacredWastePitPda,
+      })
+      .rpc();
+
+  // Run `body` with the pit in bucket storage mode and the current bucket open
+  const withBucketStorage = async (body: () => Promise<void>) => {
+    await setPitStorageMode({ buckets: {} });
+
+    try {
+      const pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      const bucketIndex = pit.totalBurns.divn(BUCKET_CAPACITY);
+      const [pitBucketPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pit_bucket"), bucketIndex.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      if (!(await provider.connection.getAccountInfo(pitBucketPda))) {
+        await program.methods
+          .openPitBucket(bucketIndex)
+          .accounts({
+            payer: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            pitBucket: pitBucketPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+
+      await body();
+    } finally {
+      await setPitStorageMode({ records: {} });
+    }
+  };
+
+  const setRewardConfig = (rewardPerBurn: anchor.BN, epochCap: anchor.BN) =>
+    program.methods
+      .setRewardConfig(rewardPerBurn, new anchor.BN(86_400), epochCap)
+      .accounts({
+        authority: authority.publicKey,
+        sacredWastePit: sacredWastePitPda,
+        pitRewards: PublicKey.findProgramAddressSync(
+          [Buffer.from("pit_rewards")],
+          program.programId
+        )[0],
+      })
+      .rpc();
+
+  // Run `body` under a temporary reward config, restoring the one set in
+  // "Burn Rewards" afterwards
+  const withRewardConfig = async (
+    rewardPerBurn: anchor.BN,
+    epochCap: anchor.BN,
+    body: () => Promise<void>
+  ) => {
+    await setRewardConfig(rewardPerBurn, epochCap);
+    try {
+      await body();
+    } finally {
+      await setRewardConfig(new anchor.BN(1_000_000), new anchor.BN(100_000_000));
+    }
+  };
+
+  // Reward token balance of `account`, zero before it exists
+  const rewardBalance = async (account: PublicKey) =>
+    (await provider.connection.getAccountInfo(account))
+      ? (await getAccount(provider.connection, account)).amount
+      : BigInt(0);
+
+  // More than the vault was stocked with, and a cap no burn can reach
+  const UNPAYABLE_REWARD = new anchor.BN("1000000000000000");
+  const NO_CAP = new anchor.BN("18446744073709551615");
+
+  // Run `body` with classic-memes charging `premiumFee` and sharing it with
+  // template creators and remix parents, restoring the initial fee and zero
+  // shares afterwards
+  const withRevenueShares = async (
+    premiumFee: number,
+    templateShareBps: number,
+    parentShareBps: number,
+    body: () => Promise<void>
+  ) => {
+    const accounts = { authority: authority.publicKey, collectionConfig: collectionConfigPda };
+    await program.methods.setPremiumFee(new anchor.BN(premiumFee)).accounts(accounts).rpc();
+    await program.methods.setTemplateShare(templateShareBps).accounts(accounts).rpc();
+    await program.methods.setParentShare(parentShareBps).accounts(accounts).rpc();
+    try {
+      await body();
+    } finally {
+      await program.methods.setTemplateShare(0).accounts(accounts).rpc();
+      await program.methods.setParentShare(0).accounts(accounts).rpc();
+      await program.methods.setPremiumFee(new anchor.BN(400_000)).accounts(accounts).rpc();
+    }
+  };
+
   describe("Initialize", () => {
     it("initializes the collection config", async () => {
       const name = "ShitpostPro";
@@ -48,7 +616,7 @@ describe("shitpost_pro", () => {
       const premiumFee = new anchor.BN(400000); // 0.0004 SOL
 
       await program.methods
-        .initialize(name, symbol, uri, treasury.publicKey, premiumFee)
+        .initialize(slug, name, symbol, uri, treasury.publicKey, premiumFee)
         .accounts({
           authority: authority.publicKey,
           collectionConfig: collectionConfigPda,
@@ -60,6 +628,7 @@ describe("shitpost_pro", () => {
         collectionConfigPda
       );
 
+      expect(config.slug).to.equal(slug);
       expect(config.name).to.equal(name);
       expect(config.symbol).to.equal(symbol);
       expect(config.uri).to.equal(uri);
@@ -68,6 +637,46 @@ describe("shitpost_pro", () => {
       expect(config.totalMinted.toNumber()).to.equal(0);
       expect(config.totalBurned.toNumber()).to.equal(0);
     });
+
+    it("initializes a second, independent collection", async () => {
+      const cursedSlug = "cursed";
+      const cursedTreasury = Keypair.generate();
+      const [cursedConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection_config"), Buffer.from(cursedSlug)],
+        program.programId
+      );
+
+      await program.methods
+        .initialize(
+          cursedSlug,
+          "Cursed",
+          "CURSED",
+          "https://shitpost.pro/cursed/",
+          cursedTreasury.publicKey,
+          new anchor.BN(1000000)
+        )
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: cursedConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const cursed = await program.account.collectionConfig.fetch(
+        cursedConfigPda
+      );
+      const classic = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+
+      expect(cursed.slug).to.equal(cursedSlug);
+      expect(cursed.treasury.toString()).to.equal(
+        cursedTreasury.publicKey.toString()
+      );
+      expect(classic.treasury.toString()).to.equal(
+        treasury.publicKey.toString()
+      );
+    });
   });
 
   describe("Sacred Waste Pit", () => {
@@ -104,45 +713,2285 @@ describe("shitpost_pro", () => {
         sacredWastePitPda.toString()
       );
     });
-  });
 
-  describe("Admin Functions", () => {
-    it("updates the treasury", async () => {
-      const newTreasury = Keypair.generate();
+    it("initializes an empty burn leaderboard", async () => {
+      const [burnLeaderboardPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("burn_leaderboard")],
+        program.programId
+      );
 
       await program.methods
-        .setTreasury(newTreasury.publicKey)
+        .initializeLeaderboard()
         .accounts({
           authority: authority.publicKey,
-          collectionConfig: collectionConfigPda,
+          sacredWastePit: sacredWastePitPda,
+          burnLeaderboard: burnLeaderboardPda,
+          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
-      const config = await program.account.collectionConfig.fetch(
-        collectionConfigPda
+      const leaderboard = await program.account.burnLeaderboard.fetch(burnLeaderboardPda);
+      expect(leaderboard.entries).to.have.length(0);
+    });
+
+    it("authorizes and revokes a depositing program", async () => {
+      const [authorizedProgramPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("authorized_program"), TOKEN_PROGRAM_ID.toBuffer()],
+        program.programId
       );
 
-      expect(config.treasury.toString()).to.equal(
-        newTreasury.publicKey.toString()
+      await program.methods
+        .authorizeProgram()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          programToAuthorize: TOKEN_PROGRAM_ID,
+          authorizedProgram: authorizedProgramPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const authorized = await program.account.authorizedProgram.fetch(
+        authorizedProgramPda
+      );
+      expect(authorized.programId.toString()).to.equal(
+        TOKEN_PROGRAM_ID.toString()
+      );
+      expect(authorized.isAuthorized).to.be.true;
+
+      await program.methods
+        .revokeProgram()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          authorizedProgram: authorizedProgramPda,
+        })
+        .rpc();
+
+      const closed = await provider.connection.getAccountInfo(
+        authorizedProgramPda
       );
+      expect(closed).to.be.null;
     });
 
-    it("updates the premium fee", async () => {
-      const newFee = new anchor.BN(500000);
+    // Build a deposit_burn instruction naming `callerProgram` and relay it
+    // through the mock caller, which signs only for its own pit_caller PDA
+    it("accepts a deposit relayed by an authorized program", async () => {
+      const mockPitCaller = anchor.workspace.MockPitCaller as Program;
+      const [authorizedProgramPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("authorized_program"), mockPitCaller.programId.toBuffer()],
+        program.programId
+      );
 
       await program.methods
-        .setPremiumFee(newFee)
+        .authorizeProgram()
         .accounts({
           authority: authority.publicKey,
-          collectionConfig: collectionConfigPda,
+          sacredWastePit: sacredWastePitPda,
+          programToAuthorize: mockPitCaller.programId,
+          authorizedProgram: authorizedProgramPda,
+          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
-      const config = await program.account.collectionConfig.fetch(
-        collectionConfigPda
+      const pitBefore = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      const { recordPda } = await relayDeposit(mockPitCaller.programId, "ipfs://relayed");
+
+      const pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      expect(pit.totalBurns.toNumber()).to.equal(pitBefore.totalBurns.toNumber() + 1);
+      const record = await program.account.pitBurnRecord.fetch(recordPda);
+      expect(record.burner.toString()).to.equal(authority.publicKey.toString());
+      expect(record.metadata).to.equal("ipfs://relayed");
+    });
+
+    it("stores a deposit's reason and message in the record and event", async () => {
+      const mockPitCaller = anchor.workspace.MockPitCaller as Program;
+      const { recordPda, signature } = await relayDeposit(
+        mockPitCaller.programId,
+        "ipfs://epitaph",
+        { reason: { rugged: {} }, message: "rest in pixels" }
       );
 
-      expect(config.premiumFee.toNumber()).to.equal(newFee.toNumber());
+      const record = await program.account.pitBurnRecord.fetch(recordPda);
+      expect(record.reason).to.deep.equal({ rugged: {} });
+      expect(record.message).to.equal("rest in pixels");
+
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, program.coder);
+      const events = [...parser.parseLogs(tx.meta.logMessages)];
+      const deposited = events.find((event) => event.name === "BurnDeposited");
+      expect(deposited.data.reason).to.deep.equal({ rugged: {} });
+      expect(deposited.data.message).to.equal("rest in pixels");
+    });
+
+    it("validates a deposit's message", async () => {
+      const mockPitCaller = anchor.workspace.MockPitCaller as Program;
+
+      for (const [message, error] of [
+        ["", "EmptyMessage"],
+        ["x".repeat(141), "MessageTooLong"],
+      ]) {
+        try {
+          await relayDeposit(mockPitCaller.programId, "ipfs://epitaph", { message });
+          expect.fail(`expected ${error}`);
+        } catch (err) {
+          expect(err.toString()).to.include(error);
+        }
+      }
+    });
+
+    it("rejects a deposit relayed by an unauthorized program", async () => {
+      const mockPitCaller = anchor.workspace.MockPitCaller as Program;
+      const [authorizedProgramPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("authorized_program"), mockPitCaller.programId.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .revokeProgram()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          authorizedProgram: authorizedProgramPda,
+        })
+        .rpc();
+
+      try {
+        await relayDeposit(mockPitCaller.programId, "ipfs://unauthorized");
+        expect.fail("expected the revoked program to be rejected");
+      } catch (err) {
+        expect(err.toString()).to.include("AccountNotInitialized");
+      }
+    });
+
+    it("rejects a deposit made in another authorized program's name", async () => {
+      // The token program is authorized, but the mock caller cannot sign for
+      // the token program's pit_caller PDA
+      const [authorizedProgramPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("authorized_program"), TOKEN_PROGRAM_ID.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .authorizeProgram()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          programToAuthorize: TOKEN_PROGRAM_ID,
+          authorizedProgram: authorizedProgramPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await relayDeposit(TOKEN_PROGRAM_ID, "ipfs://impersonated");
+        expect.fail("expected AccountNotSigner");
+      } catch (err) {
+        expect(err.toString()).to.include("AccountNotSigner");
+      }
+    });
+
+    it("switches to bucket storage and opens the current bucket", async () => {
+      await program.methods
+        .setPitStorageMode({ buckets: {} })
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+        })
+        .rpc();
+
+      const pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      expect(pit.storageMode).to.deep.equal({ buckets: {} });
+
+      const bucketIndex = pit.totalBurns.divn(BUCKET_CAPACITY);
+      const [pitBucketPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pit_bucket"), bucketIndex.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .openPitBucket(bucketIndex)
+        .accounts({
+          payer: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          pitBucket: pitBucketPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const bucket = await program.account.pitBurnBucket.fetch(pitBucketPda);
+      expect(bucket.bucketIndex.toString()).to.equal(bucketIndex.toString());
+      expect(bucket.len.toNumber()).to.equal(0);
+      expect(bucket.payer.toString()).to.equal(authority.publicKey.toString());
+
+      try {
+        await program.methods
+          .closePitBucket()
+          .accounts({
+            authority: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            pitBucket: pitBucketPda,
+            payer: authority.publicKey,
+          })
+          .rpc();
+        expect.fail("expected BucketStillActive");
+      } catch (err) {
+        expect(err.toString()).to.include("BucketStillActive");
+      }
+
+      await program.methods
+        .setPitStorageMode({ records: {} })
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+        })
+        .rpc();
+    });
+
+    it("gates burn record closing behind the archival config", async () => {
+      try {
+        await program.methods
+          .closeBurnRecord()
+          .accounts({
+            burner: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            burnedArt: null,
+            pitBurnRecord: null,
+          })
+          .rpc();
+        expect.fail("expected RecordClosingDisabled");
+      } catch (err) {
+        expect(err.toString()).to.include("RecordClosingDisabled");
+      }
+
+      await program.methods
+        .setRecordArchival(true, new anchor.BN(30 * 24 * 60 * 60))
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+        })
+        .rpc();
+
+      const pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      expect(pit.recordClosingEnabled).to.equal(true);
+      expect(pit.archivalPeriod.toNumber()).to.equal(30 * 24 * 60 * 60);
+
+      try {
+        await program.methods
+          .closeBurnRecord()
+          .accounts({
+            burner: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            burnedArt: null,
+            pitBurnRecord: null,
+          })
+          .rpc();
+        expect.fail("expected NoRecordToClose");
+      } catch (err) {
+        expect(err.toString()).to.include("NoRecordToClose");
+      }
+
+      await program.methods
+        .setRecordArchival(false, new anchor.BN(0))
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+        })
+        .rpc();
+    });
+
+    it("opens and closes a burn season", async () => {
+      const pitBefore = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      const seasonId = pitBefore.currentSeason.addn(1);
+      const [seasonPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("season"), seasonId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const now = Math.floor(Date.now() / 1000);
+
+      await program.methods
+        .openSeason(new anchor.BN(now), new anchor.BN(now + 7 * 24 * 60 * 60))
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          season: seasonPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      let pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      expect(pit.seasonActive).to.equal(true);
+      expect(pit.currentSeason.toString()).to.equal(seasonId.toString());
+
+      await program.methods
+        .closeSeason()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          season: seasonPda,
+        })
+        .rpc();
+
+      pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      expect(pit.seasonActive).to.equal(false);
+
+      const season = await program.account.season.fetch(seasonPda);
+      expect(season.seasonId.toString()).to.equal(seasonId.toString());
+      expect(season.totalBurns.toNumber()).to.equal(0);
+      expect(season.standings).to.have.length(0);
+      expect(season.closedAt).to.not.be.null;
+    });
+
+    it("allows and disallows a foreign collection", async () => {
+      const foreignCollection = Keypair.generate().publicKey;
+      const [allowedCollectionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("allowed_collection"), foreignCollection.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .allowCollection()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          collectionMint: foreignCollection,
+          allowedCollection: allowedCollectionPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const allowed = await program.account.allowedCollection.fetch(
+        allowedCollectionPda
+      );
+      expect(allowed.collectionMint.toString()).to.equal(
+        foreignCollection.toString()
+      );
+
+      await program.methods
+        .disallowCollection()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          allowedCollection: allowedCollectionPda,
+        })
+        .rpc();
+
+      expect(
+        await provider.connection.getAccountInfo(allowedCollectionPda)
+      ).to.be.null;
+    });
+  });
+
+  describe("Burn and Upgrade", () => {
+    it("sets an upgrade recipe", async () => {
+      const [recipePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("upgrade_recipe"),
+          collectionConfigPda.toBuffer(),
+          Buffer.from([0]),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .setUpgradeRecipe({ shitpost: {} }, 3, { mid: {} })
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          upgradeRecipe: recipePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const recipe = await program.account.upgradeRecipe.fetch(recipePda);
+      expect(recipe.inputCount).to.equal(3);
+      expect(recipe.inputTier).to.deep.equal({ shitpost: {} });
+      expect(recipe.outputTier).to.deep.equal({ mid: {} });
+    });
+
+    it("rejects a recipe that does not upgrade", async () => {
+      const [recipePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("upgrade_recipe"),
+          collectionConfigPda.toBuffer(),
+          Buffer.from([4]),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .setUpgradeRecipe({ fire: {} }, 2, { iconic: {} })
+          .accounts({
+            authority: authority.publicKey,
+            collectionConfig: collectionConfigPda,
+            upgradeRecipe: recipePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("expected OutputTierNotHigher");
+      } catch (err) {
+        expect(err.toString()).to.include("OutputTierNotHigher");
+      }
+    });
+  });
+
+  describe("Burn Rewards", () => {
+    it("initializes pit rewards and sets multiplier tiers", async () => {
+      const rewardMint = await createMint(
+        provider.connection,
+        (provider.wallet as anchor.Wallet).payer,
+        authority.publicKey,
+        null,
+        6
+      );
+      const [pitRewardsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pit_rewards")],
+        program.programId
+      );
+      const [rewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault")],
+        program.programId
+      );
+
+      await program.methods
+        .initializeRewards(
+          new anchor.BN(1_000_000),
+          new anchor.BN(86_400),
+          new anchor.BN(100_000_000)
+        )
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          pitRewards: pitRewardsPda,
+          rewardMint,
+          rewardVault: rewardVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      await program.methods
+        .setRewardTiers([
+          { minBurnCount: new anchor.BN(10), multiplierBps: 15_000 },
+          { minBurnCount: new anchor.BN(50), multiplierBps: 20_000 },
+        ])
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          pitRewards: pitRewardsPda,
+        })
+        .rpc();
+
+      const rewards = await program.account.pitRewards.fetch(pitRewardsPda);
+      expect(rewards.rewardMint.toString()).to.equal(rewardMint.toString());
+      expect(rewards.vault.toString()).to.equal(rewardVaultPda.toString());
+      expect(rewards.rewardPerBurn.toNumber()).to.equal(1_000_000);
+      expect(rewards.tiers).to.have.length(2);
+
+      const pit = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      expect(pit.rewardsEnabled).to.equal(true);
+
+      // Stock the vault for the burns in later tests
+      await mintTo(
+        provider.connection,
+        (provider.wallet as anchor.Wallet).payer,
+        rewardMint,
+        rewardVaultPda,
+        authority.publicKey,
+        1_000_000_000
+      );
+    });
+
+    it("pays the reward on a burn deposited by an authorized program", async () => {
+      const mockPitCaller = anchor.workspace.MockPitCaller as Program;
+      const { burnerRewardAccount } = await pitBurnAccounts(authority.publicKey);
+      const rewardBefore = await rewardBalance(burnerRewardAccount);
+
+      await relayDeposit(mockPitCaller.programId, "ipfs://rewarded");
+
+      expect((await rewardBalance(burnerRewardAccount)) > rewardBefore).to.equal(true);
+    });
+
+    it("rejects reward tiers that are not ascending", async () => {
+      const [pitRewardsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pit_rewards")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .setRewardTiers([
+            { minBurnCount: new anchor.BN(50), multiplierBps: 20_000 },
+            { minBurnCount: new anchor.BN(10), multiplierBps: 15_000 },
+          ])
+          .accounts({
+            authority: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            pitRewards: pitRewardsPda,
+          })
+          .rpc();
+        expect.fail("expected TiersNotAscending");
+      } catch (err) {
+        expect(err.toString()).to.include("TiersNotAscending");
+      }
+    });
+  });
+
+  describe("Trait Table", () => {
+    it("sets the trait weights before the drop starts", async () => {
+      const [traitTablePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("trait_table"), collectionConfigPda.toBuffer()],
+        program.programId
+      );
+      const dropStartsAt = new anchor.BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60);
+
+      await program.methods
+        .setTraitTable(
+          [50, 25, 15, 7, 3],
+          [40, 25, 20, 10, 5],
+          [30, 25, 20, 15, 10],
+          dropStartsAt
+        )
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          traitTable: traitTablePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const table = await program.account.traitTable.fetch(traitTablePda);
+      expect(table.auraWeights).to.deep.equal([50, 25, 15, 7, 3]);
+      expect(table.tierWeights).to.deep.equal([40, 25, 20, 10, 5]);
+      expect(table.classWeights).to.deep.equal([30, 25, 20, 15, 10]);
+      expect(table.dropStartsAt.toString()).to.equal(dropStartsAt.toString());
+    });
+
+    it("rejects a category without any weight", async () => {
+      const [traitTablePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("trait_table"), collectionConfigPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .setTraitTable(
+            [0, 0, 0, 0, 0],
+            [40, 25, 20, 10, 5],
+            [30, 25, 20, 15, 10],
+            new anchor.BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60)
+          )
+          .accounts({
+            authority: authority.publicKey,
+            collectionConfig: collectionConfigPda,
+            traitTable: traitTablePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("expected EmptyTraitWeights");
+      } catch (err) {
+        expect(err.toString()).to.include("EmptyTraitWeights");
+      }
+    });
+  });
+
+  describe("Templates", () => {
+    it("registers a template credited to its creator", async () => {
+      const templateHash = Keypair.generate().publicKey.toBytes();
+      const [templatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("template"), Buffer.from(templateHash)],
+        program.programId
+      );
+
+      await program.methods
+        .registerTemplate(Array.from(templateHash), "ipfs://QmDistractedBoyfriend")
+        .accounts({
+          creator: authority.publicKey,
+          template: templatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const template = await program.account.template.fetch(templatePda);
+      expect(template.creator.toString()).to.equal(authority.publicKey.toString());
+      expect(template.uri).to.equal("ipfs://QmDistractedBoyfriend");
+      expect(template.usageCount.toNumber()).to.equal(0);
+    });
+
+    const registerTemplate = async (creator: Keypair) => {
+      const templateHash = Keypair.generate().publicKey.toBytes();
+      const [templatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("template"), Buffer.from(templateHash)],
+        program.programId
+      );
+
+      await program.methods
+        .registerTemplate(Array.from(templateHash), "ipfs://QmDrakeHotlineBling")
+        .accounts({
+          creator: creator.publicKey,
+          template: templatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      return templatePda;
+    };
+
+    it("pays the template creator its share and the treasury the rest", async () => {
+      const creator = await fundedKeypair();
+      const template = await registerTemplate(creator);
+      // Funds the treasury if no mint has yet, so the balances below are clean
+      await mintNft();
+
+      await withRevenueShares(100_000_000, 2_500, 0, async () => {
+        const creatorBefore = await provider.connection.getBalance(creator.publicKey);
+        const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+        await mintNft({ template });
+
+        expect((await provider.connection.getBalance(creator.publicKey)) - creatorBefore)
+          .to.equal(25_000_000);
+        expect((await provider.connection.getBalance(treasury.publicKey)) - treasuryBefore)
+          .to.equal(75_000_000);
+      });
+
+      const credited = await program.account.template.fetch(template);
+      expect(credited.usageCount.toNumber()).to.equal(1);
+      expect(credited.totalEarned.toNumber()).to.equal(25_000_000);
+      const earnings = await program.account.creatorEarnings.fetch(
+        creatorEarningsPdaFor(creator.publicKey)
+      );
+      expect(earnings.creator.toString()).to.equal(creator.publicKey.toString());
+      expect(earnings.totalEarned.toNumber()).to.equal(25_000_000);
+    });
+
+    it("leaves a share too small to fund an empty creator wallet with the treasury", async () => {
+      const creator = await fundedKeypair();
+      const template = await registerTemplate(creator);
+      await mintNft();
+
+      // Empty the creator's wallet; the share of the 400_000 lamport fee is
+      // below the rent-exempt minimum
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: creator.publicKey,
+            toPubkey: authority.publicKey,
+            lamports: await provider.connection.getBalance(creator.publicKey),
+          })
+        ),
+        [creator]
+      );
+
+      await withRevenueShares(400_000, 2_500, 0, async () => {
+        const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+        await mintNft({ template });
+
+        expect(await provider.connection.getBalance(creator.publicKey)).to.equal(0);
+        expect((await provider.connection.getBalance(treasury.publicKey)) - treasuryBefore)
+          .to.equal(400_000);
+      });
+
+      const credited = await program.account.template.fetch(template);
+      expect(credited.usageCount.toNumber()).to.equal(1);
+      expect(credited.totalEarned.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("Mint", () => {
+    it("claims the content hash for the minted art", async () => {
+      const { mint, contentHash } = await mintNft();
+      const [contentHashRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("content_hash"), contentHash],
+        program.programId
+      );
+
+      const claim = await program.account.contentHash.fetch(contentHashRecord);
+      expect(claim.mint.toString()).to.equal(mint.toString());
+      expect(claim.artist.toString()).to.equal(authority.publicKey.toString());
+      expect(Buffer.from(claim.contentHash)).to.deep.equal(contentHash);
+    });
+
+    it("rejects a second mint of the same content hash", async () => {
+      const { contentHash } = await mintNft();
+
+      try {
+        await mintNft({ contentHash });
+        expect.fail("expected DuplicateContent");
+      } catch (err) {
+        expect(err.toString()).to.include("DuplicateContent");
+      }
+    });
+
+    it("records remix lineage and pays the parent artist", async () => {
+      const artist = await fundedKeypair();
+      const original = await mintNft({ minter: artist });
+
+      await withRevenueShares(100_000_000, 0, 2_000, async () => {
+        const artistBefore = await provider.connection.getBalance(artist.publicKey);
+        const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+        const remix = await mintNft({ parent: original.mint });
+
+        expect((await provider.connection.getBalance(artist.publicKey)) - artistBefore)
+          .to.equal(20_000_000);
+        expect((await provider.connection.getBalance(treasury.publicKey)) - treasuryBefore)
+          .to.equal(80_000_000);
+
+        const lineage = await program.account.lineage.fetch(remix.lineage);
+        expect(lineage.mint.toString()).to.equal(remix.mint.toString());
+        expect(lineage.parent.toString()).to.equal(original.mint.toString());
+        expect(lineage.root.toString()).to.equal(original.mint.toString());
+        expect(lineage.depth).to.equal(1);
+        const parent = await program.account.tokenState.fetch(original.tokenState);
+        expect(parent.remixCount.toNumber()).to.equal(1);
+
+        // A remix of the remix keeps the original as its root
+        const { lineage: grandchild } = await mintNft({ parent: remix.mint });
+        const nested = await program.account.lineage.fetch(grandchild);
+        expect(nested.parent.toString()).to.equal(remix.mint.toString());
+        expect(nested.root.toString()).to.equal(original.mint.toString());
+        expect(nested.depth).to.equal(2);
+      });
+    });
+  });
+
+  describe("Free-form URI Mint", () => {
+    const backendSigner = Keypair.generate();
+    let previousPrefixes: string[];
+
+    before(async () => {
+      const config = await program.account.collectionConfig.fetch(collectionConfigPda);
+      previousPrefixes = config.allowedUriPrefixes;
+
+      await program.methods
+        .setBackendSigner(backendSigner.publicKey)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+      await program.methods
+        .setAllowedUriPrefixes(["ipfs://"])
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setBackendSigner(null)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+      await program.methods
+        .setAllowedUriPrefixes(previousPrefixes)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+    });
+
+    it("writes a backend-approved URI to the metadata", async () => {
+      const { mint } = await mintNft({ uri: "ipfs://QmFreeForm", backendSigner });
+
+      expect((await readMetadata(mint)).uri).to.equal("ipfs://QmFreeForm");
+    });
+
+    it("requires the backend signature", async () => {
+      try {
+        await mintNft({ uri: "ipfs://QmUnapproved" });
+        expect.fail("expected BackendApprovalRequired");
+      } catch (err) {
+        expect(err.toString()).to.include("BackendApprovalRequired");
+      }
+    });
+
+    it("validates the URI", async () => {
+      for (const [uri, error] of [
+        ["https://example.com/1.json", "UriPrefixNotAllowed"],
+        ["ipfs://" + "x".repeat(200), "UriTooLong"],
+      ]) {
+        try {
+          await mintNft({ uri, backendSigner });
+          expect.fail(`expected ${error}`);
+        } catch (err) {
+          expect(err.toString()).to.include(error);
+        }
+      }
+    });
+  });
+
+  describe("Upgrades", () => {
+    let recipePda: PublicKey;
+
+    before(() => {
+      [recipePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("upgrade_recipe"), collectionConfigPda.toBuffer(), Buffer.from([0])],
+        program.programId
+      );
+    });
+
+    // Burn `inputs` under the shitpost -> mid recipe set in "Burn and Upgrade"
+    const upgrade = async (inputs: { mint: PublicKey; tokenAccount: PublicKey }[]) => {
+      const mint = Keypair.generate();
+      const [tokenTier] = PublicKey.findProgramAddressSync(
+        [Buffer.from("token_tier"), mint.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .burnAndUpgrade()
+        .accounts({
+          owner: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          upgradeRecipe: recipePda,
+          mint: mint.publicKey,
+          tokenAccount: getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey),
+          tokenTier,
+          metadata: metadataPdaFor(mint.publicKey),
+          masterEdition: editionPdaFor(mint.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          inputs.flatMap((input) => [
+            { pubkey: input.mint, isSigner: false, isWritable: true },
+            { pubkey: input.tokenAccount, isSigner: false, isWritable: true },
+            { pubkey: metadataPdaFor(input.mint), isSigner: false, isWritable: false },
+            {
+              pubkey: PublicKey.findProgramAddressSync(
+                [Buffer.from("token_tier"), input.mint.toBuffer()],
+                program.programId
+              )[0],
+              isSigner: false,
+              isWritable: true,
+            },
+            {
+              pubkey: PublicKey.findProgramAddressSync(
+                [Buffer.from("burned_art"), input.mint.toBuffer()],
+                program.programId
+              )[0],
+              isSigner: false,
+              isWritable: true,
+            },
+          ])
+        )
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 600_000 })])
+        .signers([mint])
+        .rpc();
+
+      return {
+        mint: mint.publicKey,
+        tokenAccount: getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey),
+        tokenTier,
+      };
+    };
+
+    it("rejects the wrong number of input cards", async () => {
+      const inputs = [await mintNft(), await mintNft()];
+
+      try {
+        await upgrade(inputs);
+        expect.fail("expected WrongInputCount");
+      } catch (err) {
+        expect(err.toString()).to.include("WrongInputCount");
+      }
+    });
+
+    it("rejects an input card of another tier", async () => {
+      const upgraded = await upgrade([await mintNft(), await mintNft(), await mintNft()]);
+      const tier = await program.account.tokenTier.fetch(upgraded.tokenTier);
+      expect(tier.tier).to.deep.equal({ mid: {} });
+
+      try {
+        await upgrade([upgraded, await mintNft(), await mintNft()]);
+        expect.fail("expected WrongInputTier");
+      } catch (err) {
+        expect(err.toString()).to.include("WrongInputTier");
+      }
+    });
+
+    it("records a burned input whose burned art address was pre-funded", async () => {
+      const inputs = [await mintNft(), await mintNft(), await mintNft()];
+      const [burnedArtPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("burned_art"), inputs[0].mint.toBuffer()],
+        program.programId
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: authority.publicKey,
+            toPubkey: burnedArtPda,
+            lamports: 1_000,
+          })
+        )
+      );
+
+      await upgrade(inputs);
+
+      const burnedArt = await program.account.burnedArt.fetch(burnedArtPda);
+      expect(burnedArt.originalMint.toString()).to.equal(inputs[0].mint.toString());
+      expect(burnedArt.artist.toString()).to.equal(authority.publicKey.toString());
+    });
+
+    it("rejects an input card from another collection", async () => {
+      const [cursedConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection_config"), Buffer.from("cursed")],
+        program.programId
+      );
+      const foreign = await mintNft({ collectionConfig: cursedConfigPda });
+
+      try {
+        await upgrade([foreign, await mintNft(), await mintNft()]);
+        expect.fail("expected NotInCollection");
+      } catch (err) {
+        expect(err.toString()).to.include("NotInCollection");
+      }
+    });
+  });
+
+  describe("Burn to Waste", () => {
+    it("keeps the reason and rejects messages in bucket storage mode", async () => {
+      await withBucketStorage(async () => {
+        const withMessage = await mintNft();
+        try {
+          await burnToWaste(withMessage.mint, withMessage.tokenAccount, {
+            message: "gone but not forgotten",
+          });
+          expect.fail("expected MessageNotStored");
+        } catch (err) {
+          expect(err.toString()).to.include("MessageNotStored");
+        }
+
+        const { mint, tokenAccount } = await mintNft();
+        const { burnId, pitBucket } = await burnToWaste(mint, tokenAccount, {
+          reason: { regret: {} },
+        });
+
+        const bucket = await program.account.pitBurnBucket.fetch(pitBucket);
+        const entry = bucket.entries[burnId.modn(BUCKET_CAPACITY)];
+        expect(entry.mint.toString()).to.equal(mint.toString());
+        expect(entry.sourceCollection.toString()).to.equal(collectionConfigPda.toString());
+        expect(entry.burnId.toString()).to.equal(burnId.toString());
+        // Regret is the third reason, stored one-based
+        expect(entry.reason).to.equal(3);
+      });
+    });
+
+    it("fails cleanly once the epoch cap is reached or the vault runs dry", async () => {
+      for (const [rewardPerBurn, epochCap, error] of [
+        [new anchor.BN(1_000_000), new anchor.BN(0), "EpochCapReached"],
+        [UNPAYABLE_REWARD, NO_CAP, "RewardVaultEmpty"],
+      ] as const) {
+        const { mint, tokenAccount } = await mintNft();
+
+        await withRewardConfig(rewardPerBurn, epochCap, async () => {
+          try {
+            await burnToWaste(mint, tokenAccount);
+            expect.fail(`expected ${error}`);
+          } catch (err) {
+            expect(err.toString()).to.include(error);
+          }
+        });
+
+        // The failed burn left the NFT with its holder
+        expect((await getAccount(provider.connection, tokenAccount)).amount.toString())
+          .to.equal("1");
+      }
+    });
+
+    it("lets only the burner close an archived burn record", async () => {
+      const setRecordArchival = (enabled: boolean) =>
+        program.methods
+          .setRecordArchival(enabled, new anchor.BN(0))
+          .accounts({
+            authority: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+          })
+          .rpc();
+      const closeBurnRecord = (
+        burner: PublicKey,
+        pitBurnRecord: PublicKey,
+        signers: Keypair[] = []
+      ) =>
+        program.methods
+          .closeBurnRecord()
+          .accounts({
+            burner,
+            sacredWastePit: sacredWastePitPda,
+            burnedArt: null,
+            pitBurnRecord,
+          })
+          .signers(signers)
+          .rpc();
+
+      const { mint, tokenAccount } = await mintNft();
+      const { pitBurnRecord } = await burnToWaste(mint, tokenAccount);
+      const rent = (await provider.connection.getAccountInfo(pitBurnRecord)).lamports;
+
+      await setRecordArchival(true);
+      try {
+        try {
+          const stranger = Keypair.generate();
+          await closeBurnRecord(stranger.publicKey, pitBurnRecord, [stranger]);
+          expect.fail("expected NotRecordOwner");
+        } catch (err) {
+          expect(err.toString()).to.include("NotRecordOwner");
+        }
+
+        const balanceBefore = await provider.connection.getBalance(authority.publicKey);
+        await closeBurnRecord(authority.publicKey, pitBurnRecord);
+
+        expect(await provider.connection.getAccountInfo(pitBurnRecord)).to.be.null;
+        // The rent went back to the burner, less the transaction fee
+        const balanceAfter = await provider.connection.getBalance(authority.publicKey);
+        expect(balanceAfter - balanceBefore).to.be.closeTo(rent, 10_000);
+      } finally {
+        await setRecordArchival(false);
+      }
+    });
+
+    it("burns verified NFTs of an allowed foreign collection only", async () => {
+      const allowedCollectionPdaFor = (collectionMint: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("allowed_collection"), collectionMint.toBuffer()],
+          program.programId
+        )[0];
+      const burnForeign = async (
+        mint: PublicKey,
+        tokenAccount: PublicKey,
+        allowedCollection: PublicKey
+      ) => {
+        const pitBurn = await pitBurnAccounts(authority.publicKey);
+        await program.methods
+          .burnForeignToPit()
+          .accounts({
+            burner: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            allowedCollection: allowedCollectionPdaFor(allowedCollection),
+            mint,
+            tokenAccount,
+            metadata: metadataPdaFor(mint),
+            masterEdition: editionPdaFor(mint),
+            collectionMetadata: metadataPdaFor(allowedCollection),
+            ...pitBurn.accounts,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+            ...pitBurn.preInstructions,
+          ])
+          .rpc();
+        return pitBurn;
+      };
+
+      const { mint: collection } = await mintForeignNft();
+      const { mint: otherCollection } = await mintForeignNft();
+      await program.methods
+        .allowCollection()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          collectionMint: collection,
+          allowedCollection: allowedCollectionPdaFor(collection),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const member = await mintForeignNft(collection);
+      const { pitBurnRecord } = await burnForeign(member.mint, member.tokenAccount, collection);
+
+      const record = await program.account.pitBurnRecord.fetch(pitBurnRecord);
+      expect(record.sourceCollection.toString()).to.equal(collection.toString());
+      expect(record.metadata).to.equal("https://example.com/foreign.json");
+      expect(record.reason).to.be.null;
+      expect(await provider.connection.getAccountInfo(metadataPdaFor(member.mint))).to.be.null;
+
+      const outsider = await mintForeignNft(otherCollection);
+      try {
+        await burnForeign(outsider.mint, outsider.tokenAccount, collection);
+        expect.fail("expected CollectionNotAllowed");
+      } catch (err) {
+        expect(err.toString()).to.include("CollectionNotAllowed");
+      }
+
+      await program.methods
+        .disallowCollection()
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          allowedCollection: allowedCollectionPdaFor(collection),
+        })
+        .rpc();
+
+      // Once disallowed, the collection has no allowlist entry to burn against
+      const lateMember = await mintForeignNft(collection);
+      try {
+        await burnForeign(lateMember.mint, lateMember.tokenAccount, collection);
+        expect.fail("expected AccountNotInitialized");
+      } catch (err) {
+        expect(err.toString()).to.include("AccountNotInitialized");
+      }
+      expect((await getAccount(provider.connection, lateMember.tokenAccount)).amount.toString())
+        .to.equal("1");
+    });
+
+    it("ranks the burner on the leaderboard", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      await burnToWaste(mint, tokenAccount);
+
+      const [burnerStatsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("burner_stats"), authority.publicKey.toBuffer()],
+        program.programId
+      );
+      const [burnLeaderboardPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("burn_leaderboard")],
+        program.programId
+      );
+      const stats = await program.account.burnerStats.fetch(burnerStatsPda);
+      const leaderboard = await program.account.burnLeaderboard.fetch(burnLeaderboardPda);
+      const entry = leaderboard.entries.find((e) => e.burner.equals(authority.publicKey));
+      expect(entry.burnCount.toString()).to.equal(stats.burnCount.toString());
+    });
+
+    it("requires the season accounts while a season is open", async () => {
+      const pitBefore = await program.account.sacredWastePit.fetch(sacredWastePitPda);
+      const seasonId = pitBefore.currentSeason.addn(1);
+      const [seasonPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("season"), seasonId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [seasonStatsPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("season_stats"),
+          seasonId.toArrayLike(Buffer, "le", 8),
+          authority.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const now = Math.floor(Date.now() / 1000);
+
+      await program.methods
+        .openSeason(new anchor.BN(now - 60), new anchor.BN(now + 7 * 24 * 60 * 60))
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+          season: seasonPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        const skipped = await mintNft();
+        try {
+          await burnToWaste(skipped.mint, skipped.tokenAccount, { omitSeason: true });
+          expect.fail("expected WrongSeasonAccounts");
+        } catch (err) {
+          expect(err.toString()).to.include("WrongSeasonAccounts");
+        }
+
+        const { mint, tokenAccount } = await mintNft();
+        await burnToWaste(mint, tokenAccount);
+
+        const season = await program.account.season.fetch(seasonPda);
+        expect(season.totalBurns.toNumber()).to.equal(1);
+        expect(season.standings[0].burner.toString()).to.equal(authority.publicKey.toString());
+        const seasonStats = await program.account.seasonBurnerStats.fetch(seasonStatsPda);
+        expect(seasonStats.burnCount.toNumber()).to.equal(1);
+      } finally {
+        await program.methods
+          .closeSeason()
+          .accounts({
+            authority: authority.publicKey,
+            sacredWastePit: sacredWastePitPda,
+            season: seasonPda,
+          })
+          .rpc();
+      }
+    });
+  });
+
+  describe("Delayed Burns", () => {
+    const scheduledBurnPdaFor = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("scheduled_burn"), mint.toBuffer()],
+        program.programId
+      )[0];
+
+    const escrowPdaFor = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("burn_escrow"), mint.toBuffer()],
+        program.programId
+      )[0];
+
+    const setCancelWindow = (seconds: number) =>
+      program.methods
+        .setCancelWindow(new anchor.BN(seconds))
+        .accounts({
+          authority: authority.publicKey,
+          sacredWastePit: sacredWastePitPda,
+        })
+        .rpc();
+
+    const scheduleBurn = async (mint: PublicKey, tokenAccount: PublicKey) => {
+      await program.methods
+        .scheduleBurn({ sacrifice: {} }, "see you on the other side")
+        .accounts({
+          burner: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          sacredWastePit: sacredWastePitPda,
+          mint,
+          tokenAccount,
+          metadata: metadataPdaFor(mint),
+          scheduledBurn: scheduledBurnPdaFor(mint),
+          escrow: escrowPdaFor(mint),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+    };
+
+    const cancelBurn = (mint: PublicKey, tokenAccount: PublicKey) =>
+      program.methods
+        .cancelBurn()
+        .accounts({
+          burner: authority.publicKey,
+          mint,
+          tokenAccount,
+          scheduledBurn: scheduledBurnPdaFor(mint),
+          escrow: escrowPdaFor(mint),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const executeBurn = async (
+      mint: PublicKey,
+      executor: Keypair,
+      options: { omitRewards?: boolean } = {}
+    ) => {
+      const pitBurn = await pitBurnAccounts(authority.publicKey, options);
+
+      await program.methods
+        .executeBurn()
+        .accounts({
+          executor: executor.publicKey,
+          burner: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          sacredWastePit: sacredWastePitPda,
+          mint,
+          scheduledBurn: scheduledBurnPdaFor(mint),
+          escrow: escrowPdaFor(mint),
+          burnedArt: PublicKey.findProgramAddressSync(
+            [Buffer.from("burned_art"), mint.toBuffer()],
+            program.programId
+          )[0],
+          ...pitBurn.accounts,
+          metadata: metadataPdaFor(mint),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions(pitBurn.preInstructions)
+        .signers([executor])
+        .rpc();
+
+      return pitBurn;
+    };
+
+    it("escrows the NFT and returns it when cancelled within the window", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      await scheduleBurn(mint, tokenAccount);
+
+      const scheduled = await program.account.scheduledBurn.fetch(scheduledBurnPdaFor(mint));
+      expect(scheduled.burner.toString()).to.equal(authority.publicKey.toString());
+      expect(scheduled.reason).to.deep.equal({ sacrifice: {} });
+      expect((await getAccount(provider.connection, escrowPdaFor(mint))).amount.toString())
+        .to.equal("1");
+      expect((await getAccount(provider.connection, tokenAccount)).amount.toString())
+        .to.equal("0");
+
+      await cancelBurn(mint, tokenAccount);
+
+      expect((await getAccount(provider.connection, tokenAccount)).amount.toString())
+        .to.equal("1");
+      expect(await provider.connection.getAccountInfo(escrowPdaFor(mint))).to.be.null;
+      expect(await provider.connection.getAccountInfo(scheduledBurnPdaFor(mint))).to.be.null;
+    });
+
+    it("refuses to execute while the cancel window is open", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      await scheduleBurn(mint, tokenAccount);
+
+      try {
+        await executeBurn(mint, await fundedKeypair());
+        expect.fail("expected CancelWindowOpen");
+      } catch (err) {
+        expect(err.toString()).to.include("CancelWindowOpen");
+      }
+
+      await cancelBurn(mint, tokenAccount);
+    });
+
+    it("lets anyone execute once the window closes, paying the burner's reward", async () => {
+      await setCancelWindow(1);
+
+      try {
+        const { mint, tokenAccount } = await mintNft();
+        await scheduleBurn(mint, tokenAccount);
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+
+        try {
+          await cancelBurn(mint, tokenAccount);
+          expect.fail("expected CancelWindowClosed");
+        } catch (err) {
+          expect(err.toString()).to.include("CancelWindowClosed");
+        }
+
+        const executor = await fundedKeypair();
+        try {
+          await executeBurn(mint, executor, { omitRewards: true });
+          expect.fail("expected WrongRewardAccounts");
+        } catch (err) {
+          expect(err.toString()).to.include("WrongRewardAccounts");
+        }
+
+        const { burnerRewardAccount } = await pitBurnAccounts(authority.publicKey);
+        const rewardBefore = await rewardBalance(burnerRewardAccount);
+
+        const { pitBurnRecord } = await executeBurn(mint, executor);
+
+        const record = await program.account.pitBurnRecord.fetch(pitBurnRecord);
+        expect(record.burner.toString()).to.equal(authority.publicKey.toString());
+        expect(record.reason).to.deep.equal({ sacrifice: {} });
+        expect(record.message).to.equal("see you on the other side");
+        expect((await rewardBalance(burnerRewardAccount)) > rewardBefore).to.equal(true);
+        expect(await provider.connection.getAccountInfo(escrowPdaFor(mint))).to.be.null;
+        expect(await provider.connection.getAccountInfo(scheduledBurnPdaFor(mint))).to.be.null;
+      } finally {
+        await setCancelWindow(60 * 60);
+      }
+    });
+
+    it("stores the burn without its message if the pit switched to buckets", async () => {
+      await setCancelWindow(1);
+
+      try {
+        const { mint, tokenAccount } = await mintNft();
+        await scheduleBurn(mint, tokenAccount);
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+
+        await withBucketStorage(async () => {
+          const { burnId, pitBucket } = await executeBurn(mint, await fundedKeypair());
+
+          const bucket = await program.account.pitBurnBucket.fetch(pitBucket);
+          const entry = bucket.entries[burnId.modn(BUCKET_CAPACITY)];
+          expect(entry.mint.toString()).to.equal(mint.toString());
+          // Sacrifice is the fourth reason, stored one-based
+          expect(entry.reason).to.equal(4);
+        });
+
+        expect(await provider.connection.getAccountInfo(escrowPdaFor(mint))).to.be.null;
+      } finally {
+        await setCancelWindow(60 * 60);
+      }
+    });
+
+    it("skips the reward once the epoch cap is reached or the vault runs dry", async () => {
+      await setCancelWindow(1);
+
+      try {
+        const capped = await mintNft();
+        const unfunded = await mintNft();
+        await scheduleBurn(capped.mint, capped.tokenAccount);
+        await scheduleBurn(unfunded.mint, unfunded.tokenAccount);
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+
+        const executor = await fundedKeypair();
+        const { burnerRewardAccount } = await pitBurnAccounts(authority.publicKey);
+
+        for (const [{ mint }, rewardPerBurn, epochCap] of [
+          [capped, new anchor.BN(1_000_000), new anchor.BN(0)],
+          [unfunded, UNPAYABLE_REWARD, NO_CAP],
+        ] as const) {
+          await withRewardConfig(rewardPerBurn, epochCap, async () => {
+            const rewardBefore = await rewardBalance(burnerRewardAccount);
+
+            await executeBurn(mint, executor);
+
+            expect(await rewardBalance(burnerRewardAccount)).to.equal(rewardBefore);
+            expect(await provider.connection.getAccountInfo(escrowPdaFor(mint))).to.be.null;
+          });
+        }
+      } finally {
+        await setCancelWindow(60 * 60);
+      }
+    });
+  });
+
+  describe("Burn Certificates", () => {
+    const burnerStatsPda = () =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("burner_stats"), authority.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+    it("mints a frozen certificate naming the burned mint and burn id", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      const statsBefore = await program.account.burnerStats.fetchNullable(burnerStatsPda());
+
+      const { burnId, certificateMint, certificateTokenAccount } = await burnToWaste(
+        mint,
+        tokenAccount,
+        { certificate: true }
+      );
+
+      const certificate = await getAccount(provider.connection, certificateTokenAccount);
+      expect(certificate.amount.toString()).to.equal("1");
+      expect(certificate.isFrozen).to.equal(true);
+
+      const metadata = await readMetadata(certificateMint);
+      expect(metadata.name).to.equal(`Burn Cert #${burnId.toString()}`);
+      expect(metadata.uri).to.equal(`https://shitpost.pro/metadata/burned/${mint.toString()}.json`);
+
+      const stats = await program.account.burnerStats.fetch(burnerStatsPda());
+      expect(stats.certificatesMinted.toNumber()).to.equal(
+        (statsBefore?.certificatesMinted.toNumber() ?? 0) + 1
+      );
+    });
+
+    it("cannot transfer a certificate", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      const { certificateMint, certificateTokenAccount } = await burnToWaste(mint, tokenAccount, {
+        certificate: true,
+      });
+      const payer = (provider.wallet as anchor.Wallet).payer;
+      const recipient = await createAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        certificateMint,
+        Keypair.generate().publicKey
+      );
+
+      try {
+        await transfer(provider.connection, payer, certificateTokenAccount, recipient, payer, 1);
+        expect.fail("expected the frozen certificate transfer to fail");
+      } catch (err) {
+        expect((err.logs ?? []).join("\n")).to.include("Account is frozen");
+      }
+    });
+  });
+
+  describe("Moderation", () => {
+    let moderatorPda: PublicKey;
+
+    before(async () => {
+      [moderatorPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("moderator"),
+          collectionConfigPda.toBuffer(),
+          authority.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+    });
+
+    const frozenNftPdaFor = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("frozen_nft"), mint.toBuffer()],
+        program.programId
+      )[0];
+
+    const nextModerationActionPda = async () => {
+      const record = await program.account.moderator.fetch(moderatorPda);
+      return PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("moderation_action"),
+          moderatorPda.toBuffer(),
+          record.actionCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+    };
+
+    const moderate = async (
+      method: "freezeNft" | "thawNft",
+      mint: PublicKey,
+      reasonCode: number
+    ) => {
+      const moderationActionPda = await nextModerationActionPda();
+
+      await program.methods[method](reasonCode)
+        .accounts({
+          moderator: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          moderatorRecord: moderatorPda,
+          mint,
+          metadata: metadataPdaFor(mint),
+          frozenNft: frozenNftPdaFor(mint),
+          moderationAction: moderationActionPda,
+          systemProgram: SystemProgram.programId,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        })
+        .rpc();
+
+      return program.account.moderationAction.fetch(moderationActionPda);
+    };
+
+    it("adds a moderator", async () => {
+      await program.methods
+        .addModerator(authority.publicKey)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          moderatorRecord: moderatorPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const record = await program.account.moderator.fetch(moderatorPda);
+      expect(record.moderator.toString()).to.equal(authority.publicKey.toString());
+    });
+
+    it("takes an NFT's metadata down on freeze and restores it on thaw", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      const { uri: originalUri } = await readMetadata(mint);
+      const config = await program.account.collectionConfig.fetch(collectionConfigPda);
+
+      const freeze = await moderate("freezeNft", mint, 3);
+      expect(freeze.kind).to.deep.equal({ freeze: {} });
+      expect(freeze.reasonCode).to.equal(3);
+      expect(freeze.moderator.toString()).to.equal(authority.publicKey.toString());
+      expect((await readMetadata(mint)).uri).to.equal(`${config.uri}moderated.json`);
+
+      const frozen = await program.account.frozenNft.fetch(frozenNftPdaFor(mint));
+      expect(frozen.mint.toString()).to.equal(mint.toString());
+      expect(frozen.originalUri).to.equal(originalUri);
+
+      // Only the content is taken down; the token account stays transferable
+      expect((await getAccount(provider.connection, tokenAccount)).isFrozen).to.equal(false);
+
+      try {
+        await moderate("freezeNft", mint, 3);
+        expect.fail("expected AlreadyFrozen");
+      } catch (err) {
+        expect(err.toString()).to.include("AlreadyFrozen");
+      }
+
+      const thaw = await moderate("thawNft", mint, 0);
+      expect(thaw.kind).to.deep.equal({ thaw: {} });
+      expect((await readMetadata(mint)).uri).to.equal(originalUri);
+      expect(await provider.connection.getAccountInfo(frozenNftPdaFor(mint))).to.be.null;
+    });
+
+    it("rejects mints frozen by the config PDA, like burn certificates", async () => {
+      const payer = (provider.wallet as anchor.Wallet).payer;
+      const mint = await createMint(
+        provider.connection,
+        payer,
+        authority.publicKey,
+        collectionConfigPda,
+        0
+      );
+
+      try {
+        await moderate("freezeNft", mint, 0);
+        expect.fail("expected CertificateMint");
+      } catch (err) {
+        expect(err.toString()).to.include("CertificateMint");
+      }
+    });
+
+    it("refuses to take down a real burn certificate", async () => {
+      const { mint, tokenAccount } = await mintNft();
+      const { certificateMint } = await burnToWaste(mint, tokenAccount, {
+        certificate: true,
+      });
+
+      try {
+        await moderate("freezeNft", certificateMint, 0);
+        expect.fail("expected CertificateMint");
+      } catch (err) {
+        expect(err.toString()).to.include("CertificateMint");
+      }
+    });
+
+    const BOND_LAMPORTS = 10_000_000;
+
+    const reportPdaFor = (mint: PublicKey, reporter: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("report"), mint.toBuffer(), reporter.toBuffer()],
+        program.programId
+      )[0];
+
+    const report = async (mint: PublicKey, reporter: Keypair, reasonCode: number) => {
+      await program.methods
+        .reportNft(reasonCode)
+        .accounts({
+          reporter: reporter.publicKey,
+          collectionConfig: collectionConfigPda,
+          mint,
+          metadata: metadataPdaFor(mint),
+          report: reportPdaFor(mint, reporter.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reporter])
+        .rpc();
+      return reportPdaFor(mint, reporter.publicKey);
+    };
+
+    const noFreeze = {
+      mint: null,
+      metadata: null,
+      frozenNft: null,
+      moderationAction: null,
+      tokenMetadataProgram: null,
+    };
+
+    it("stakes the bond on a report and rejects a duplicate", async () => {
+      const { mint } = await mintNft();
+      const reporter = await fundedKeypair();
+      const balanceBefore = await provider.connection.getBalance(reporter.publicKey);
+
+      const reportPda = await report(mint, reporter, 7);
+
+      const stored = await program.account.report.fetch(reportPda);
+      expect(stored.reporter.toString()).to.equal(reporter.publicKey.toString());
+      expect(stored.reasonCode).to.equal(7);
+      expect(stored.bond.toNumber()).to.equal(BOND_LAMPORTS);
+
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        (await provider.connection.getAccountInfo(reportPda)).data.length
+      );
+      expect(await provider.connection.getBalance(reportPda)).to.equal(rent + BOND_LAMPORTS);
+      expect(await provider.connection.getBalance(reporter.publicKey)).to.be.at.most(
+        balanceBefore - rent - BOND_LAMPORTS
+      );
+
+      try {
+        await report(mint, reporter, 7);
+        expect.fail("expected the report PDA to already exist");
+      } catch (err) {
+        expect((err.logs ?? []).join("\n")).to.include("already in use");
+      }
+    });
+
+    it("refunds the bond and freezes the NFT on an upheld report", async () => {
+      const { mint } = await mintNft();
+      const { uri: originalUri } = await readMetadata(mint);
+      const reporter = await fundedKeypair();
+      const reportPda = await report(mint, reporter, 2);
+      const reportLamports = await provider.connection.getBalance(reportPda);
+      const reporterBefore = await provider.connection.getBalance(reporter.publicKey);
+      const moderationActionPda = await nextModerationActionPda();
+
+      await program.methods
+        .resolveReport(true)
+        .accounts({
+          moderator: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          moderatorRecord: moderatorPda,
+          report: reportPda,
+          reporter: reporter.publicKey,
+          treasury: (await program.account.collectionConfig.fetch(collectionConfigPda)).treasury,
+          mint,
+          metadata: metadataPdaFor(mint),
+          frozenNft: frozenNftPdaFor(mint),
+          moderationAction: moderationActionPda,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(reportPda)).to.be.null;
+      expect(await provider.connection.getBalance(reporter.publicKey)).to.equal(
+        reporterBefore + reportLamports
+      );
+      const frozen = await program.account.frozenNft.fetch(frozenNftPdaFor(mint));
+      expect(frozen.originalUri).to.equal(originalUri);
+
+      const action = await program.account.moderationAction.fetch(moderationActionPda);
+      expect(action.kind).to.deep.equal({ freeze: {} });
+      expect(action.reasonCode).to.equal(2);
+    });
+
+    it("slashes the bond to the treasury on a dismissed report", async () => {
+      const { mint } = await mintNft();
+      const reporter = await fundedKeypair();
+      const reportPda = await report(mint, reporter, 1);
+      const { treasury: treasuryKey } = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      const reportLamports = await provider.connection.getBalance(reportPda);
+      const reporterBefore = await provider.connection.getBalance(reporter.publicKey);
+      const treasuryBefore = await provider.connection.getBalance(treasuryKey);
+
+      await program.methods
+        .resolveReport(false)
+        .accounts({
+          moderator: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          moderatorRecord: moderatorPda,
+          report: reportPda,
+          reporter: reporter.publicKey,
+          treasury: treasuryKey,
+          ...noFreeze,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(reportPda)).to.be.null;
+      expect(await provider.connection.getBalance(reporter.publicKey)).to.equal(
+        reporterBefore + reportLamports - BOND_LAMPORTS
+      );
+      expect(await provider.connection.getBalance(treasuryKey)).to.equal(
+        treasuryBefore + BOND_LAMPORTS
+      );
+    });
+
+    it("removes a moderator", async () => {
+      await program.methods
+        .removeModerator()
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+          moderatorRecord: moderatorPda,
+        })
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(moderatorPda)).to.be.null;
+    });
+  });
+
+  describe("Reveal", () => {
+    const backendSigner = Keypair.generate();
+    const sha256 = (data: Buffer) => createHash("sha256").update(data).digest();
+
+    let traitTablePda: PublicKey;
+
+    before(async () => {
+      [traitTablePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("trait_table"), collectionConfigPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .setBackendSigner(backendSigner.publicKey)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+      await program.methods
+        .setAllowedUriPrefixes(["ipfs://"])
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setBackendSigner(null)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+    });
+
+    const reveal = async (
+      mint: PublicKey,
+      tokenState: PublicKey,
+      uri: string,
+      signer = backendSigner
+    ) =>
+      program.methods
+        .reveal(uri)
+        .accounts({
+          backendSigner: signer.publicKey,
+          collectionConfig: collectionConfigPda,
+          mint,
+          tokenState,
+          metadata: metadataPdaFor(mint),
+          frozenNft: PublicKey.findProgramAddressSync(
+            [Buffer.from("frozen_nft"), mint.toBuffer()],
+            program.programId
+          )[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+
+    const revealTraits = async (mint: PublicKey, tokenState: PublicKey, nonce: Buffer) =>
+      program.methods
+        .revealTraits([...nonce])
+        .accounts({
+          payer: authority.publicKey,
+          mint,
+          tokenState,
+          traitTable: traitTablePda,
+          tokenTraits: tokenTraitsPdaFor(mint),
+          tokenTier: tokenTierPdaFor(mint),
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // Wait until the slot a token's trait roll is committed to has passed
+    const waitForCommitSlot = async (tokenState: PublicKey) => {
+      const { commitSlot } = await program.account.tokenState.fetch(tokenState);
+      while ((await provider.connection.getSlot()) <= commitSlot.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 400));
+      }
+    };
+
+    it("rejects a reveal from anyone but the backend signer", async () => {
+      const { mint, tokenState } = await mintNft();
+
+      try {
+        await reveal(mint, tokenState, "ipfs://QmRevealed", Keypair.generate());
+        expect.fail("expected InvalidBackendSigner");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidBackendSigner");
+      }
+    });
+
+    it("validates the revealed URI", async () => {
+      const { mint, tokenState } = await mintNft();
+
+      for (const [uri, error] of [
+        ["", "EmptyUri"],
+        ["ipfs://" + "x".repeat(200), "UriTooLong"],
+        ["https://example.com/1.json", "UriPrefixNotAllowed"],
+      ]) {
+        try {
+          await reveal(mint, tokenState, uri);
+          expect.fail(`expected ${error}`);
+        } catch (err) {
+          expect(err.toString()).to.include(error);
+        }
+      }
+    });
+
+    it("reveals the metadata URI exactly once", async () => {
+      const { mint, tokenState } = await mintNft();
+
+      await reveal(mint, tokenState, "ipfs://QmRevealed");
+      const state = await program.account.tokenState.fetch(tokenState);
+      expect(state.revealed).to.equal(true);
+
+      try {
+        await reveal(mint, tokenState, "ipfs://QmRevealedAgain");
+        expect.fail("expected AlreadyRevealed");
+      } catch (err) {
+        expect(err.toString()).to.include("AlreadyRevealed");
+      }
+    });
+
+    it("rolls committed traits once and serves them through get_token_traits", async () => {
+      const nonce = Keypair.generate().publicKey.toBuffer();
+      const { mint, tokenState } = await mintNft({
+        commitment: [...sha256(nonce)],
+        backendSigner,
+      });
+
+      // The roll waits for the hash of a slot after the mint
+      try {
+        await revealTraits(mint, tokenState, nonce);
+        expect.fail("expected CommitSlotNotReached");
+      } catch (err) {
+        expect(err.toString()).to.include("CommitSlotNotReached");
+      }
+
+      try {
+        await revealTraits(mint, tokenState, Keypair.generate().publicKey.toBuffer());
+        expect.fail("expected CommitmentMismatch");
+      } catch (err) {
+        expect(err.toString()).to.include("CommitmentMismatch");
+      }
+
+      await waitForCommitSlot(tokenState);
+      await revealTraits(mint, tokenState, nonce);
+      const stored = await program.account.tokenTraits.fetch(tokenTraitsPdaFor(mint));
+      expect(stored.mint.toString()).to.equal(mint.toString());
+      // The rolled tier lives in the token tier record upgrades read
+      const tier = await program.account.tokenTier.fetch(tokenTierPdaFor(mint));
+      expect(tier.mint.toString()).to.equal(mint.toString());
+
+      const traits = await program.methods
+        .getTokenTraits()
+        .accounts({ mint, tokenTraits: tokenTraitsPdaFor(mint) })
+        .view();
+      expect(traits).to.deep.equal(stored.traits);
+
+      try {
+        await revealTraits(mint, tokenState, nonce);
+        expect.fail("expected the second reveal to fail");
+      } catch (err) {
+        expect((err.logs ?? []).join("\n")).to.include("already in use");
+      }
+    });
+
+    it("lets a collection that already minted set its first trait table once", async () => {
+      const [cursedConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection_config"), Buffer.from("cursed")],
+        program.programId
+      );
+      const [cursedTablePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("trait_table"), cursedConfigPda.toBuffer()],
+        program.programId
+      );
+      const setCursedTable = () =>
+        program.methods
+          .setTraitTable(
+            [20, 20, 20, 20, 20],
+            [20, 20, 20, 20, 20],
+            [20, 20, 20, 20, 20],
+            new anchor.BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60)
+          )
+          .accounts({
+            authority: authority.publicKey,
+            collectionConfig: cursedConfigPda,
+            traitTable: cursedTablePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+      const config = await program.account.collectionConfig.fetch(cursedConfigPda);
+      expect(config.totalMinted.toNumber()).to.be.greaterThan(0);
+
+      await setCursedTable();
+      const table = await program.account.traitTable.fetch(cursedTablePda);
+      expect(table.collectionConfig.toString()).to.equal(cursedConfigPda.toString());
+
+      try {
+        await setCursedTable();
+        expect.fail("expected TraitTableLocked");
+      } catch (err) {
+        expect(err.toString()).to.include("TraitTableLocked");
+      }
+    });
+
+    it("rejects a trait reveal for a token minted without a commitment", async () => {
+      const { mint, tokenState } = await mintNft();
+
+      try {
+        await revealTraits(mint, tokenState, Keypair.generate().publicKey.toBuffer());
+        expect.fail("expected NoCommitment");
+      } catch (err) {
+        expect(err.toString()).to.include("NoCommitment");
+      }
+    });
+  });
+
+  describe("VRF Randomness", () => {
+    const mockVrf = anchor.workspace.MockVrf as Program;
+    let traitTablePda: PublicKey;
+
+    before(async () => {
+      [traitTablePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("trait_table"), collectionConfigPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .setVrfOracle(mockVrf.programId)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setVrfOracle(null)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+    });
+
+    // Accounts of `fulfill_randomness` after the oracle authority
+    const fulfillAccounts = (mint: PublicKey, tokenState: PublicKey) => ({
+      payer: authority.publicKey,
+      mint,
+      vrfRequest: vrfRequestPdaFor(mint),
+      tokenState,
+      traitTable: traitTablePda,
+      tokenTraits: tokenTraitsPdaFor(mint),
+      tokenTier: tokenTierPdaFor(mint),
+      systemProgram: SystemProgram.programId,
+    });
+
+    it("rolls traits when the oracle fulfils a mint's randomness request", async () => {
+      const { mint, tokenState } = await mintNft({ vrfOracle: mockVrf.programId });
+
+      const request = await program.account.vrfRequest.fetch(vrfRequestPdaFor(mint));
+      expect(request.oracle.toString()).to.equal(mockVrf.programId.toString());
+      expect(request.fulfilled).to.equal(false);
+
+      const accounts = fulfillAccounts(mint, tokenState);
+      await mockVrf.methods
+        .fulfill([...Keypair.generate().publicKey.toBytes()])
+        .accounts({
+          oracleAuthority: PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle_authority")],
+            mockVrf.programId
+          )[0],
+          consumerProgram: program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: accounts.payer, isSigner: true, isWritable: true },
+          { pubkey: accounts.mint, isSigner: false, isWritable: false },
+          { pubkey: accounts.vrfRequest, isSigner: false, isWritable: true },
+          { pubkey: accounts.tokenState, isSigner: false, isWritable: false },
+          { pubkey: accounts.traitTable, isSigner: false, isWritable: false },
+          { pubkey: accounts.tokenTraits, isSigner: false, isWritable: true },
+          { pubkey: accounts.tokenTier, isSigner: false, isWritable: true },
+          { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+        ])
+        .rpc();
+
+      expect((await program.account.vrfRequest.fetch(vrfRequestPdaFor(mint))).fulfilled)
+        .to.equal(true);
+      const traits = await program.account.tokenTraits.fetch(tokenTraitsPdaFor(mint));
+      expect(traits.mint.toString()).to.equal(mint.toString());
+      const tier = await program.account.tokenTier.fetch(tokenTierPdaFor(mint));
+      expect(tier.mint.toString()).to.equal(mint.toString());
+    });
+
+    it("rejects a callback not signed by the request's oracle", async () => {
+      const { mint, tokenState } = await mintNft({ vrfOracle: mockVrf.programId });
+      const impostor = Keypair.generate();
+
+      try {
+        await program.methods
+          .fulfillRandomness([...Keypair.generate().publicKey.toBytes()])
+          .accounts({
+            oracleAuthority: impostor.publicKey,
+            ...fulfillAccounts(mint, tokenState),
+          })
+          .signers([impostor])
+          .rpc();
+        expect.fail("expected InvalidOracleAuthority");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidOracleAuthority");
+      }
+    });
+  });
+
+  describe("Admin Functions", () => {
+    it("updates the treasury", async () => {
+      const newTreasury = Keypair.generate();
+
+      await program.methods
+        .setTreasury(newTreasury.publicKey)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+
+      expect(config.treasury.toString()).to.equal(
+        newTreasury.publicKey.toString()
+      );
+    });
+
+    it("updates the premium fee", async () => {
+      const newFee = new anchor.BN(500000);
+
+      await program.methods
+        .setPremiumFee(newFee)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+
+      expect(config.premiumFee.toNumber()).to.equal(newFee.toNumber());
+    });
+
+    it("sets and clears the backend signer", async () => {
+      const backendSigner = Keypair.generate();
+
+      await program.methods
+        .setBackendSigner(backendSigner.publicKey)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      let config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      expect(config.backendSigner.toString()).to.equal(
+        backendSigner.publicKey.toString()
+      );
+
+      await program.methods
+        .setBackendSigner(null)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      expect(config.backendSigner).to.be.null;
+    });
+
+    it("sets and clears the VRF oracle", async () => {
+      const mockVrf = anchor.workspace.MockVrf as Program;
+
+      await program.methods
+        .setVrfOracle(mockVrf.programId)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      let config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      expect(config.vrfOracle.toString()).to.equal(mockVrf.programId.toString());
+
+      await program.methods
+        .setVrfOracle(null)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      config = await program.account.collectionConfig.fetch(collectionConfigPda);
+      expect(config.vrfOracle).to.be.null;
+    });
+
+    it("sets the template creator share", async () => {
+      await program.methods
+        .setTemplateShare(2_500)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      expect(config.templateShareBps).to.equal(2_500);
+    });
+
+    it("rejects a template share above 100%", async () => {
+      try {
+        await program.methods
+          .setTemplateShare(10_001)
+          .accounts({
+            authority: authority.publicKey,
+            collectionConfig: collectionConfigPda,
+          })
+          .rpc();
+        expect.fail("expected InvalidShareBps");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidShareBps");
+      }
+    });
+
+    it("sets the remix parent share", async () => {
+      await program.methods
+        .setParentShare(2_000)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      expect(config.parentShareBps).to.equal(2_000);
+    });
+
+    it("rejects template and parent shares above 100% combined", async () => {
+      try {
+        await program.methods
+          .setParentShare(8_000)
+          .accounts({
+            authority: authority.publicKey,
+            collectionConfig: collectionConfigPda,
+          })
+          .rpc();
+        expect.fail("expected InvalidShareBps");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidShareBps");
+      }
+    });
+
+    it("sets the allowed URI prefixes", async () => {
+      const prefixes = ["ipfs://", "https://gateway.pinata.cloud/ipfs/"];
+
+      await program.methods
+        .setAllowedUriPrefixes(prefixes)
+        .accounts({
+          authority: authority.publicKey,
+          collectionConfig: collectionConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.collectionConfig.fetch(
+        collectionConfigPda
+      );
+      expect(config.allowedUriPrefixes).to.deep.equal(prefixes);
+    });
+
+    it("rejects an overlong URI prefix", async () => {
+      try {
+        await program.methods
+          .setAllowedUriPrefixes(["ipfs://" + "x".repeat(64)])
+          .accounts({
+            authority: authority.publicKey,
+            collectionConfig: collectionConfigPda,
+          })
+          .rpc();
+        expect.fail("expected InvalidUriPrefix");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidUriPrefix");
+      }
     });
   });
 
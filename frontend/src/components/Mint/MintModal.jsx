@@ -71,11 +71,16 @@ export default function MintModal({ isOpen, onClose, onSuccess, imageDataURL, on
       const { metadataUrl } = await upload(blob, tokenId)
       console.log('IPFS upload complete:', metadataUrl)
 
+      // The program rejects a second mint of the same image
+      const contentHash = new Uint8Array(
+        await crypto.subtle.digest('SHA-256', await blob.arrayBuffer())
+      )
+
       setStep('minting')
 
       // Call Solana mint with premium fee
       console.log('Minting on Solana...')
-      await mintWithPremium(metadataUrl, solanaIdl)
+      await mintWithPremium(metadataUrl, solanaIdl, contentHash)
     } catch (err) {
       console.error('Mint error:', err)
       const errorMessage = err?.shortMessage || err?.message || 'Unknown error'
@@ -59,6 +59,9 @@ export const getTreasuryAddress = (network) => {
   return config.treasury
 }
 
+// Collection hosted by the program; the config PDA is seeded by its slug
+export const COLLECTION_SLUG = import.meta.env.VITE_SOLANA_COLLECTION_SLUG || 'classic-memes'
+
 // PDA seeds for deriving account addresses
 export const PDA_SEEDS = {
   COLLECTION_CONFIG: 'collection_config',
@@ -67,12 +70,18 @@ export const PDA_SEEDS = {
   PIT_BURN: 'pit_burn',
   BURNER_STATS: 'burner_stats',
   AUTHORIZED_PROGRAM: 'authorized_program',
+  TOKEN_STATE: 'token_state',
+  CONTENT_HASH: 'content_hash',
+  BURN_LEADERBOARD: 'burn_leaderboard',
+  SEASON: 'season',
+  SEASON_STATS: 'season_stats',
+  PIT_REWARDS: 'pit_rewards',
 }
 
 // Derive PDA addresses
-export const deriveCollectionConfigPda = (programId) => {
+export const deriveCollectionConfigPda = (programId, slug = COLLECTION_SLUG) => {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.COLLECTION_CONFIG)],
+    [Buffer.from(PDA_SEEDS.COLLECTION_CONFIG), Buffer.from(slug)],
     programId
   )
 }
@@ -91,9 +100,57 @@ export const deriveBurnedArtPda = (programId, mintPubkey) => {
   )
 }
 
+export const deriveTokenStatePda = (programId, mintPubkey) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.TOKEN_STATE), mintPubkey.toBuffer()],
+    programId
+  )
+}
+
+export const deriveContentHashPda = (programId, contentHash) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.CONTENT_HASH), Buffer.from(contentHash)],
+    programId
+  )
+}
+
 export const deriveBurnerStatsPda = (programId, burnerPubkey) => {
   return PublicKey.findProgramAddressSync(
     [Buffer.from(PDA_SEEDS.BURNER_STATS), burnerPubkey.toBuffer()],
     programId
   )
 }
+
+export const deriveBurnLeaderboardPda = (programId) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.BURN_LEADERBOARD)],
+    programId
+  )
+}
+
+const seasonIdBuffer = (seasonId) => {
+  const buffer = Buffer.alloc(8)
+  buffer.writeBigUInt64LE(BigInt(seasonId.toString()))
+  return buffer
+}
+
+export const deriveSeasonPda = (programId, seasonId) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.SEASON), seasonIdBuffer(seasonId)],
+    programId
+  )
+}
+
+export const deriveSeasonStatsPda = (programId, seasonId, burnerPubkey) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.SEASON_STATS), seasonIdBuffer(seasonId), burnerPubkey.toBuffer()],
+    programId
+  )
+}
+
+export const derivePitRewardsPda = (programId) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.PIT_REWARDS)],
+    programId
+  )
+}
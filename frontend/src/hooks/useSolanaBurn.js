@@ -1,7 +1,11 @@
 import { useState, useCallback } from 'react'
 import { useConnection, useWallet } from '@solana/wallet-adapter-react'
 import { PublicKey, SystemProgram } from '@solana/web3.js'
-import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token'
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountIdempotentInstruction,
+} from '@solana/spl-token'
 import { Program, AnchorProvider } from '@coral-xyz/anchor'
 import {
   getSolanaNetwork,
@@ -9,6 +13,10 @@ import {
   deriveSacredWastePitPda,
   deriveBurnedArtPda,
   deriveBurnerStatsPda,
+  deriveBurnLeaderboardPda,
+  deriveSeasonPda,
+  deriveSeasonStatsPda,
+  derivePitRewardsPda,
 } from '../config/solana'
 
 // Metaplex Token Metadata Program ID
@@ -113,8 +121,9 @@ export default function useSolanaBurn(network = 'devnet') {
     }
   }, [connection, wallet, networkConfig])
 
-  // Burn to Sacred Waste Pit
-  const burnToWaste = useCallback(async (mintAddress, idl) => {
+  // Burn to Sacred Waste Pit with a reason ('cringe', 'rugged', 'regret' or
+  // 'sacrifice') and an optional epitaph
+  const burnToWaste = useCallback(async (mintAddress, idl, { reason = 'cringe', message = null } = {}) => {
     if (!wallet.publicKey || !wallet.signTransaction) {
       throw new Error('Wallet not connected')
     }
@@ -159,6 +168,35 @@ export default function useSolanaBurn(network = 'devnet') {
         networkConfig.programId
       )
 
+      // Burns must be counted toward the open season, if any
+      const [burnLeaderboardPda] = deriveBurnLeaderboardPda(networkConfig.programId)
+      const [seasonPda] = deriveSeasonPda(networkConfig.programId, pitState.currentSeason)
+      const [seasonStatsPda] = deriveSeasonStatsPda(
+        networkConfig.programId,
+        pitState.currentSeason,
+        wallet.publicKey
+      )
+
+      // Once pit rewards are live every burn pays out, to the burner's
+      // reward token account (created here if needed)
+      const [pitRewardsPda] = derivePitRewardsPda(networkConfig.programId)
+      const pitRewards = pitState.rewardsEnabled
+        ? await program.account.pitRewards.fetch(pitRewardsPda)
+        : null
+      const burnerRewardAccount = pitRewards
+        ? getAssociatedTokenAddressSync(pitRewards.rewardMint, wallet.publicKey)
+        : null
+      const preInstructions = pitRewards
+        ? [
+            createAssociatedTokenAccountIdempotentInstruction(
+              wallet.publicKey,
+              burnerRewardAccount,
+              wallet.publicKey,
+              pitRewards.rewardMint
+            ),
+          ]
+        : []
+
       // Get token account
       const tokenAccount = getAssociatedTokenAddressSync(mint, wallet.publicKey)
 
@@ -166,7 +204,7 @@ export default function useSolanaBurn(network = 'devnet') {
 
       // Call the burn to waste instruction
       const tx = await program.methods
-        .burnToWaste()
+        .burnToWaste({ [reason]: {} }, message || null)
         .accounts({
           burner: wallet.publicKey,
           collectionConfig: collectionConfigPda,
@@ -176,10 +214,17 @@ export default function useSolanaBurn(network = 'devnet') {
           burnedArt: burnedArtPda,
           pitBurnRecord: pitBurnRecordPda,
           burnerStats: burnerStatsPda,
+          burnLeaderboard: burnLeaderboardPda,
+          season: pitState.seasonActive ? seasonPda : null,
+          seasonStats: pitState.seasonActive ? seasonStatsPda : null,
+          pitRewards: pitRewards ? pitRewardsPda : null,
+          rewardVault: pitRewards ? pitRewards.vault : null,
+          burnerRewardAccount,
           metadata: metadataPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
+        .preInstructions(preInstructions)
         .rpc({ skipPreflight: true })
 
       setSignature(tx)
@@ -3,19 +3,16 @@ import { useConnection, useWallet } from '@solana/wallet-adapter-react'
 import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, Keypair } from '@solana/web3.js'
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token'
 import { Program, AnchorProvider } from '@coral-xyz/anchor'
-import { getSolanaNetwork } from '../config/solana'
+import {
+  getSolanaNetwork,
+  deriveCollectionConfigPda,
+  deriveTokenStatePda,
+  deriveContentHashPda,
+} from '../config/solana'
 
 // Metaplex Token Metadata Program ID
 const TOKEN_METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s')
 
-// Derive collection config PDA
-const deriveCollectionConfigPda = (programId) => {
-  return PublicKey.findProgramAddressSync(
-    [Buffer.from('collection_config')],
-    programId
-  )
-}
-
 // Derive metadata PDA
 const deriveMetadataPda = (mint) => {
   return PublicKey.findProgramAddressSync(
@@ -64,10 +61,12 @@ export default function useSolanaMint(network = 'devnet') {
    * Mint NFT with premium fee using our stripped-down contract
    *
    * Contract: 7F6SJmYgF8iEF9DQmpDUuboTRs4qYt5hr27TcXCuykDo
-   * Instruction: mintWithPremium(uri: string)
+   * Instruction: mintWithPremium(uri: string | null, commitment: null, contentHash: [u8; 32])
    * Fee: 0.015 SOL (set on-chain)
+   *
+   * `contentHash` is the SHA-256 of the rendered image; each hash mints once.
    */
-  const mintWithPremium = useCallback(async (uri, idl) => {
+  const mintWithPremium = useCallback(async (uri, idl, contentHash) => {
     if (!wallet.publicKey || !wallet.signTransaction) {
       throw new Error('Wallet not connected')
     }
@@ -76,6 +75,10 @@ export default function useSolanaMint(network = 'devnet') {
       throw new Error('IDL not provided')
     }
 
+    if (!contentHash || contentHash.length !== 32) {
+      throw new Error('32-byte content hash required')
+    }
+
     setIsPending(true)
     setError(null)
     setIsSuccess(false)
@@ -112,6 +115,8 @@ export default function useSolanaMint(network = 'devnet') {
       // Derive PDAs
       const [metadataPda] = deriveMetadataPda(mintKeypair.publicKey)
       const [masterEditionPda] = deriveMasterEditionPda(mintKeypair.publicKey)
+      const [tokenStatePda] = deriveTokenStatePda(networkConfig.programId, mintKeypair.publicKey)
+      const [contentHashPda] = deriveContentHashPda(networkConfig.programId, contentHash)
       console.log('[Mint] Metadata PDA:', metadataPda.toString())
       console.log('[Mint] Master Edition PDA:', masterEditionPda.toString())
 
@@ -127,13 +132,15 @@ export default function useSolanaMint(network = 'devnet') {
       // Call mintWithPremium instruction via Anchor
       console.log('[Mint] Sending transaction...')
       const tx = await program.methods
-        .mintWithPremium(uri)
+        .mintWithPremium(uri, null, Array.from(contentHash))
         .accounts({
           minter: wallet.publicKey,
           collectionConfig: collectionConfigPda,
           treasury: config.treasury, // Use on-chain treasury
           mint: mintKeypair.publicKey,
           tokenAccount: tokenAccount,
+          tokenState: tokenStatePda,
+          contentHashRecord: contentHashPda,
           metadata: metadataPda,
           masterEdition: masterEditionPda,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -167,6 +174,8 @@ export default function useSolanaMint(network = 'devnet') {
           errorMessage = 'Insufficient SOL balance'
         } else if (logStr.includes('ConstraintSeeds')) {
           errorMessage = 'Account mismatch - wrong network?'
+        } else if (logStr.includes('already in use')) {
+          errorMessage = 'This meme has already been minted'
         }
       }
 